@@ -0,0 +1,65 @@
+// Generates invocations of impl_swizzles! from src/types/swizzles.rs, so that the xyzw, rgba and stpq names
+// of every swizzle are derived from the component lists instead of being typed out
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const NAME_SETS: [[&str; 4]; 3] = [["x", "y", "z", "w"], ["r", "g", "b", "a"], ["s", "t", "p", "q"]];
+
+fn sequences(size: usize, length: usize, distinct: bool) -> Vec<Vec<usize>> {
+    let mut result = vec![Vec::new()];
+    for _ in 0..length {
+        let mut next = Vec::new();
+        for prefix in result {
+            for index in 0..size {
+                if !distinct || !prefix.contains(&index) {
+                    let mut sequence = prefix.clone();
+                    sequence.push(index);
+                    next.push(sequence);
+                }
+            }
+        }
+        result = next;
+    }
+    result
+}
+
+fn name(set: usize, sequence: &[usize]) -> String {
+    sequence.iter().map(|&index| NAME_SETS[set][index]).collect()
+}
+
+fn fields(sequence: &[usize]) -> String {
+    sequence.iter().map(|&index| NAME_SETS[0][index]).collect::<Vec<_>>().join(", ")
+}
+
+fn swizzles(types: &str, size: usize) -> String {
+    let mut code = format!("impl_swizzles!([{}]\n    {{\n", types);
+    for length in 2..=4 {
+        for sequence in sequences(size, length, false) {
+            code += &format!("        {} {} {}: {};\n", name(0, &sequence), name(1, &sequence), name(2, &sequence), fields(&sequence));
+        }
+    }
+
+    code += "    } {\n";
+    for length in 2..=size {
+        for sequence in sequences(size, length, true) {
+            code += &format!("        set_{} set_{} set_{}: {};\n", name(0, &sequence), name(1, &sequence), name(2, &sequence), fields(&sequence));
+        }
+    }
+
+    code += "    }\n);\n";
+    code
+}
+
+fn main() {
+    let code = [
+        swizzles("TVector2 vectors::Vector2::empty", 2),
+        swizzles("TVector3 vectors::Vector3::empty", 3),
+        swizzles("TVector4 vectors::Vector4::empty, TQuaternion quaternions::Quaternion::identity", 4)
+    ].join("\n");
+
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("swizzles.rs");
+    fs::write(path, code).unwrap();
+    println!("cargo:rerun-if-changed=build.rs");
+}
\ No newline at end of file
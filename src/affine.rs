@@ -0,0 +1,9 @@
+use crate::extended::affine::*;
+
+pub use crate::types::basic_types::NotAffineError;
+
+pub type Affine2 = F32Affine2;
+pub type FAffine2 = F32Affine2;
+
+pub type Affine3 = F32Affine3;
+pub type FAffine3 = F32Affine3;
\ No newline at end of file
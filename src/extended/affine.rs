@@ -0,0 +1,7 @@
+use crate::types::basic_types::{ TAffine2, TAffine3 };
+
+pub type F32Affine2 = TAffine2<f32>;
+pub type F64Affine2 = TAffine2<f64>;
+
+pub type F32Affine3 = TAffine3<f32>;
+pub type F64Affine3 = TAffine3<f64>;
\ No newline at end of file
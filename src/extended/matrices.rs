@@ -0,0 +1,32 @@
+use crate::types::basic_types::{ TMatrix2, TMatrix3, TMatrix4 };
+use crate::types::basic_types::{ TMatrix2x3, TMatrix2x4, TMatrix3x2, TMatrix3x4, TMatrix4x2, TMatrix4x3, TDecomposition };
+
+pub type F32Matrix2 = TMatrix2<f32>;
+pub type F64Matrix2 = TMatrix2<f64>;
+
+pub type F32Matrix3 = TMatrix3<f32>;
+pub type F64Matrix3 = TMatrix3<f64>;
+
+pub type F32Matrix4 = TMatrix4<f32>;
+pub type F64Matrix4 = TMatrix4<f64>;
+
+pub type F32Matrix2x3 = TMatrix2x3<f32>;
+pub type F64Matrix2x3 = TMatrix2x3<f64>;
+
+pub type F32Matrix2x4 = TMatrix2x4<f32>;
+pub type F64Matrix2x4 = TMatrix2x4<f64>;
+
+pub type F32Matrix3x2 = TMatrix3x2<f32>;
+pub type F64Matrix3x2 = TMatrix3x2<f64>;
+
+pub type F32Matrix3x4 = TMatrix3x4<f32>;
+pub type F64Matrix3x4 = TMatrix3x4<f64>;
+
+pub type F32Matrix4x2 = TMatrix4x2<f32>;
+pub type F64Matrix4x2 = TMatrix4x2<f64>;
+
+pub type F32Matrix4x3 = TMatrix4x3<f32>;
+pub type F64Matrix4x3 = TMatrix4x3<f64>;
+
+pub type F32Decomposition = TDecomposition<f32>;
+pub type F64Decomposition = TDecomposition<f64>;
\ No newline at end of file
@@ -1,2 +1,4 @@
 pub mod vectors;
-pub mod quaternions;
\ No newline at end of file
+pub mod quaternions;
+pub mod matrices;
+pub mod affine;
\ No newline at end of file
@@ -0,0 +1,161 @@
+//! GLSL common functions which work on floating-point scalars and component-wise on vectors
+//! ```
+//! use iomath::functions::{ mix, smoothstep };
+//! use iomath::vectors::Vector2;
+//! 
+//! assert_eq!(mix(1.0, 3.0, 0.5), 2.0);
+//! assert_eq!(smoothstep(Vector2::new(0.0, 0.0), Vector2::new(1.0, 2.0), Vector2::new(0.5, 1.0)), Vector2::new(0.5, 0.5));
+//! ```
+
+use crate::types::basic_types::{ TVector2, TVector3, TVector4 };
+use crate::types::basic_types::num_traits::Float;
+
+/// Floating-point scalar or vector (GLSL genType) supported by the functions of this module
+pub trait Common : Copy {
+    /// Returns x * (1 - a) + y * a
+    fn mix(self, y: Self, a: Self) -> Self;
+
+    /// Returns 0 if self is less than edge and 1 otherwise
+    fn step(self, edge: Self) -> Self;
+
+    /// Returns Hermite interpolation 3t² - 2t³ of t = saturate((self - edge0) / (edge1 - edge0))
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self;
+
+    /// Returns Perlin's interpolation 6t⁵ - 15t⁴ + 10t³ of t = saturate((self - edge0) / (edge1 - edge0))
+    fn smootherstep(self, edge0: Self, edge1: Self) -> Self;
+
+    /// Returns t such that mix(a, b, t) equals self
+    fn inverse_lerp(self, a: Self, b: Self) -> Self;
+
+    /// Maps self linearly from in_range onto out_range, without clamping
+    fn remap(self, in_range: (Self, Self), out_range: (Self, Self)) -> Self;
+
+    /// Returns self clamped between 0 and 1
+    fn saturate(self) -> Self;
+
+    /// Returns self - y * floor(self / y), whose sign follows y like GLSL mod
+    fn modulo(self, y: Self) -> Self;
+}
+
+impl<T> Common for T where T : Float {
+    fn mix(self, y: Self, a: Self) -> Self {
+        self * (T::one() - a) + y * a
+    }
+
+    fn step(self, edge: Self) -> Self {
+        if self < edge { T::zero() } else { T::one() }
+    }
+
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+        let t = self.inverse_lerp(edge0, edge1).saturate();
+        t * t * (T::cast(3.0) - T::cast(2.0) * t)
+    }
+
+    fn smootherstep(self, edge0: Self, edge1: Self) -> Self {
+        let t = self.inverse_lerp(edge0, edge1).saturate();
+        t * t * t * (t * (t * T::cast(6.0) - T::cast(15.0)) + T::cast(10.0))
+    }
+
+    fn inverse_lerp(self, a: Self, b: Self) -> Self {
+        (self - a) / (b - a)
+    }
+
+    fn remap(self, in_range: (Self, Self), out_range: (Self, Self)) -> Self {
+        out_range.0.mix(out_range.1, self.inverse_lerp(in_range.0, in_range.1))
+    }
+
+    fn saturate(self) -> Self {
+        self.max(T::zero()).min(T::one())
+    }
+
+    fn modulo(self, y: Self) -> Self {
+        self - y * (self / y).floor()
+    }
+}
+
+macro_rules! impl_common_vector {
+    ($vector:ident { $($field:ident),+ }) => {
+        impl<T> Common for $vector<T> where T : Common {
+            fn mix(self, y: Self, a: Self) -> Self {
+                $vector { $($field: self.$field.mix(y.$field, a.$field)),+ }
+            }
+
+            fn step(self, edge: Self) -> Self {
+                $vector { $($field: self.$field.step(edge.$field)),+ }
+            }
+
+            fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+                $vector { $($field: self.$field.smoothstep(edge0.$field, edge1.$field)),+ }
+            }
+
+            fn smootherstep(self, edge0: Self, edge1: Self) -> Self {
+                $vector { $($field: self.$field.smootherstep(edge0.$field, edge1.$field)),+ }
+            }
+
+            fn inverse_lerp(self, a: Self, b: Self) -> Self {
+                $vector { $($field: self.$field.inverse_lerp(a.$field, b.$field)),+ }
+            }
+
+            fn remap(self, in_range: (Self, Self), out_range: (Self, Self)) -> Self {
+                $vector { $($field: self.$field.remap((in_range.0.$field, in_range.1.$field), (out_range.0.$field, out_range.1.$field))),+ }
+            }
+
+            fn saturate(self) -> Self {
+                $vector { $($field: self.$field.saturate()),+ }
+            }
+
+            fn modulo(self, y: Self) -> Self {
+                $vector { $($field: self.$field.modulo(y.$field)),+ }
+            }
+        }
+    };
+}
+
+impl_common_vector!(TVector2 { x, y });
+impl_common_vector!(TVector3 { x, y, z });
+impl_common_vector!(TVector4 { x, y, z, w });
+
+/// Linearly interpolates between x and y by a, i.e. x * (1 - a) + y * a (GLSL mix)
+pub fn mix<T>(x: T, y: T, a: T) -> T where T : Common {
+    x.mix(y, a)
+}
+
+/// Same as mix (HLSL lerp)
+pub fn lerp<T>(x: T, y: T, a: T) -> T where T : Common {
+    x.mix(y, a)
+}
+
+/// Returns 0 where x is less than edge and 1 otherwise
+pub fn step<T>(edge: T, x: T) -> T where T : Common {
+    x.step(edge)
+}
+
+/// Returns smooth Hermite interpolation between 0 and 1 of x between edge0 and edge1
+pub fn smoothstep<T>(edge0: T, edge1: T, x: T) -> T where T : Common {
+    x.smoothstep(edge0, edge1)
+}
+
+/// Returns smoother (C2 continuous) interpolation between 0 and 1 of x between edge0 and edge1
+pub fn smootherstep<T>(edge0: T, edge1: T, x: T) -> T where T : Common {
+    x.smootherstep(edge0, edge1)
+}
+
+/// Returns t such that mix(a, b, t) equals value
+pub fn inverse_lerp<T>(a: T, b: T, value: T) -> T where T : Common {
+    value.inverse_lerp(a, b)
+}
+
+/// Maps value linearly from in_range (start, end) onto out_range (start, end), without clamping
+pub fn remap<T>(value: T, in_range: (T, T), out_range: (T, T)) -> T where T : Common {
+    value.remap(in_range, out_range)
+}
+
+/// Returns x clamped between 0 and 1 (HLSL saturate)
+pub fn saturate<T>(x: T) -> T where T : Common {
+    x.saturate()
+}
+
+/// Returns x - y * floor(x / y), whose sign follows y (GLSL mod, which is a keyword in Rust)
+pub fn modulo<T>(x: T, y: T) -> T where T : Common {
+    x.modulo(y)
+}
\ No newline at end of file
@@ -2,4 +2,7 @@ pub mod types;
 pub mod extended;
 
 pub mod vectors;
-pub mod quaternions;
\ No newline at end of file
+pub mod quaternions;
+pub mod matrices;
+pub mod affine;
+pub mod functions;
\ No newline at end of file
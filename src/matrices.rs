@@ -0,0 +1,31 @@
+use crate::extended::matrices::*;
+
+pub type Matrix2 = F32Matrix2;
+pub type FMatrix2 = F32Matrix2;
+
+pub type Matrix3 = F32Matrix3;
+pub type FMatrix3 = F32Matrix3;
+
+pub type Matrix4 = F32Matrix4;
+pub type FMatrix4 = F32Matrix4;
+
+pub type Matrix2x3 = F32Matrix2x3;
+pub type FMatrix2x3 = F32Matrix2x3;
+
+pub type Matrix2x4 = F32Matrix2x4;
+pub type FMatrix2x4 = F32Matrix2x4;
+
+pub type Matrix3x2 = F32Matrix3x2;
+pub type FMatrix3x2 = F32Matrix3x2;
+
+pub type Matrix3x4 = F32Matrix3x4;
+pub type FMatrix3x4 = F32Matrix3x4;
+
+pub type Matrix4x2 = F32Matrix4x2;
+pub type FMatrix4x2 = F32Matrix4x2;
+
+pub type Matrix4x3 = F32Matrix4x3;
+pub type FMatrix4x3 = F32Matrix4x3;
+
+pub type Decomposition = F32Decomposition;
+pub type FDecomposition = F32Decomposition;
\ No newline at end of file
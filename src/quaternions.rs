@@ -1,3 +1,5 @@
 use crate::extended::quaternions::F32Quaternion;
 
+pub use crate::types::basic_types::{ EulerOrder, ParseError };
+
 pub type Quaternion = F32Quaternion;
\ No newline at end of file
@@ -0,0 +1,411 @@
+use crate::types::basic_types::{ TVector2, TVector3, TVector4, TQuaternion, TMatrix2, TMatrix3, TMatrix4, TAffine2, TAffine3, NotAffineError };
+use crate::types::basic_types::num_traits::{ Num, Float };
+
+use std::convert::TryFrom;
+use std::ops::*;
+use std::fmt::*;
+
+impl<T> TAffine2<T> where T : Num {
+    /// Creates TAffine2&lt;type&gt; which leaves points unchanged
+    /// ```
+    /// use iomath::affine::Affine2;
+    /// use iomath::matrices::Matrix2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let affine = Affine2::identity();
+    /// assert_eq!(affine, Affine2::new(Matrix2::identity(), Vector2::new(0.0, 0.0)));
+    /// ```
+    pub fn identity() -> Self {
+        Self::new(TMatrix2::identity(), TVector2::empty())
+    }
+
+    /// Creates TAffine2&lt;type&gt; which applies linear part matrix, then translates by translation
+    /// ```
+    /// use iomath::affine::Affine2;
+    /// use iomath::matrices::Matrix2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let affine = Affine2::new(Matrix2::from_diagonal(Vector2::new(2.0, 3.0)), Vector2::new(1.0, 1.0));
+    /// assert_eq!(affine.transform_point2(Vector2::new(1.0, 1.0)), Vector2::new(3.0, 4.0));
+    /// ```
+    pub fn new(matrix: TMatrix2<T>, translation: TVector2<T>) -> Self {
+        Self {
+            matrix,
+            translation
+        }
+    }
+
+    /// Creates TAffine2&lt;type&gt; which translates points by translation
+    /// ```
+    /// use iomath::affine::Affine2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let affine = Affine2::from_translation(Vector2::new(1.0, 2.0));
+    /// assert_eq!(affine.transform_point2(Vector2::new(1.0, 1.0)), Vector2::new(2.0, 3.0));
+    /// ```
+    pub fn from_translation(translation: TVector2<T>) -> Self {
+        Self::new(TMatrix2::identity(), translation)
+    }
+
+    /// Creates TAffine2&lt;type&gt; which scales x and y by components of scale
+    /// ```
+    /// use iomath::affine::Affine2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let affine = Affine2::from_scale(Vector2::new(2.0, -1.0));
+    /// assert_eq!(affine.transform_vector2(Vector2::new(1.0, 1.0)), Vector2::new(2.0, -1.0));
+    /// ```
+    pub fn from_scale(scale: TVector2<T>) -> Self {
+        Self::new(TMatrix2::from_diagonal(scale), TVector2::empty())
+    }
+
+    /// Transforms point, so translation is applied
+    /// ```
+    /// use iomath::affine::Affine2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let affine = Affine2::from_translation(Vector2::new(1.0, 2.0));
+    /// assert_eq!(affine.transform_point2(Vector2::new(0.0, 0.0)), Vector2::new(1.0, 2.0));
+    /// ```
+    pub fn transform_point2(&self, point: TVector2<T>) -> TVector2<T> {
+        self.matrix * point + self.translation
+    }
+
+    /// Transforms direction vector, so translation is not applied
+    /// ```
+    /// use iomath::affine::Affine2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let affine = Affine2::from_translation(Vector2::new(1.0, 2.0));
+    /// assert_eq!(affine.transform_vector2(Vector2::new(0.0, 1.0)), Vector2::new(0.0, 1.0));
+    /// ```
+    pub fn transform_vector2(&self, vector: TVector2<T>) -> TVector2<T> {
+        self.matrix * vector
+    }
+}
+
+impl<T> TAffine2<T> where T : Float {
+    /// Returns inverse transform, which only inverts the 2x2 linear part instead of the full 3x3 matrix,
+    /// or None if the linear part is not invertible
+    /// ```
+    /// use iomath::affine::Affine2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let affine = Affine2::from_translation(Vector2::new(1.0, 2.0)) * Affine2::from_scale(Vector2::new(2.0, 4.0));
+    /// assert_eq!(affine.inverse().unwrap().transform_point2(Vector2::new(3.0, 6.0)), Vector2::new(1.0, 1.0));
+    /// assert_eq!(Affine2::from_scale(Vector2::new(0.0, 1.0)).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let matrix = self.matrix.inverse()?;
+        Some(Self::new(matrix, -(matrix * self.translation)))
+    }
+
+    /// Creates TAffine2&lt;type&gt; which rotates counter-clockwise by angle (in radians)
+    /// ```
+    /// use iomath::affine::Affine2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let affine = Affine2::from_angle(std::f32::consts::FRAC_PI_2);
+    /// assert!(affine.transform_vector2(Vector2::new(1.0, 0.0)).distance(Vector2::new(0.0, 1.0)) < 1e-6);
+    /// ```
+    pub fn from_angle(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(TMatrix2::new(TVector2::new(cos, sin), TVector2::new(-sin, cos)), TVector2::empty())
+    }
+
+    /// Creates TAffine2&lt;type&gt; which scales, then rotates counter-clockwise by angle (in radians), then translates
+    /// ```
+    /// use iomath::affine::Affine2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let affine = Affine2::from_angle_translation_scale(std::f32::consts::PI, Vector2::new(1.0, 0.0), Vector2::new(2.0, 2.0));
+    /// assert!(affine.transform_point2(Vector2::new(1.0, 0.0)).distance(Vector2::new(-1.0, 0.0)) < 1e-6);
+    /// ```
+    pub fn from_angle_translation_scale(angle: T, translation: TVector2<T>, scale: TVector2<T>) -> Self {
+        let rotation = Self::from_angle(angle).matrix;
+        Self::new(TMatrix2::new(rotation.x * scale.x, rotation.y * scale.y), translation)
+    }
+}
+
+impl<T> TAffine3<T> where T : Num {
+    /// Creates TAffine3&lt;type&gt; which leaves points unchanged
+    /// ```
+    /// use iomath::affine::Affine3;
+    /// use iomath::matrices::Matrix3;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let affine = Affine3::identity();
+    /// assert_eq!(affine, Affine3::new(Matrix3::identity(), Vector3::new(0.0, 0.0, 0.0)));
+    /// ```
+    pub fn identity() -> Self {
+        Self::new(TMatrix3::identity(), TVector3::empty())
+    }
+
+    /// Creates TAffine3&lt;type&gt; which applies linear part matrix, then translates by translation
+    /// ```
+    /// use iomath::affine::Affine3;
+    /// use iomath::matrices::Matrix3;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let affine = Affine3::new(Matrix3::from_diagonal(Vector3::new(2.0, 3.0, 4.0)), Vector3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(affine.transform_point3(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(3.0, 4.0, 5.0));
+    /// ```
+    pub fn new(matrix: TMatrix3<T>, translation: TVector3<T>) -> Self {
+        Self {
+            matrix,
+            translation
+        }
+    }
+
+    /// Creates TAffine3&lt;type&gt; which translates points by translation
+    /// ```
+    /// use iomath::affine::Affine3;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let affine = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(affine.transform_point3(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(2.0, 3.0, 4.0));
+    /// ```
+    pub fn from_translation(translation: TVector3<T>) -> Self {
+        Self::new(TMatrix3::identity(), translation)
+    }
+
+    /// Creates TAffine3&lt;type&gt; which scales x, y and z by components of scale
+    /// ```
+    /// use iomath::affine::Affine3;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let affine = Affine3::from_scale(Vector3::new(2.0, 3.0, -1.0));
+    /// assert_eq!(affine.transform_vector3(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(2.0, 3.0, -1.0));
+    /// ```
+    pub fn from_scale(scale: TVector3<T>) -> Self {
+        Self::new(TMatrix3::from_diagonal(scale), TVector3::empty())
+    }
+
+    /// Creates TAffine3&lt;type&gt; which rotates by normalized quaternion
+    /// ```
+    /// use iomath::affine::Affine3;
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let affine = Affine3::from_quaternion(Quaternion::new(0.0, 0.0, 0.0, 1.0));
+    /// assert_eq!(affine.transform_vector3(Vector3::new(1.0, 0.0, 0.0)), Vector3::new(-1.0, 0.0, 0.0));
+    /// ```
+    pub fn from_quaternion(rotation: TQuaternion<T>) -> Self {
+        Self::new(TMatrix3::from(rotation), TVector3::empty())
+    }
+
+    /// Creates TAffine3&lt;type&gt; which scales, then rotates by normalized rotation, then translates
+    /// ```
+    /// use iomath::affine::Affine3;
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let affine = Affine3::from_rotation_translation_scale(Quaternion::new(0.0, 0.0, 0.0, 1.0), Vector3::new(1.0, 0.0, 0.0), Vector3::new(2.0, 2.0, 2.0));
+    /// assert_eq!(affine.transform_point3(Vector3::new(1.0, 0.0, 0.0)), Vector3::new(-1.0, 0.0, 0.0));
+    /// ```
+    pub fn from_rotation_translation_scale(rotation: TQuaternion<T>, translation: TVector3<T>, scale: TVector3<T>) -> Self {
+        let rotation = TMatrix3::from(rotation);
+        Self::new(TMatrix3::new(rotation.x * scale.x, rotation.y * scale.y, rotation.z * scale.z), translation)
+    }
+
+    /// Transforms point, so translation is applied
+    /// ```
+    /// use iomath::affine::Affine3;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let affine = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(affine.transform_point3(Vector3::new(0.0, 0.0, 0.0)), Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn transform_point3(&self, point: TVector3<T>) -> TVector3<T> {
+        self.matrix * point + self.translation
+    }
+
+    /// Transforms direction vector, so translation is not applied
+    /// ```
+    /// use iomath::affine::Affine3;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let affine = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(affine.transform_vector3(Vector3::new(0.0, 0.0, 1.0)), Vector3::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn transform_vector3(&self, vector: TVector3<T>) -> TVector3<T> {
+        self.matrix * vector
+    }
+}
+
+impl<T> TAffine3<T> where T : Float {
+    /// Returns inverse transform, which only inverts the 3x3 linear part instead of the full 4x4 matrix,
+    /// or None if the linear part is not invertible
+    /// ```
+    /// use iomath::affine::Affine3;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let affine = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0)) * Affine3::from_scale(Vector3::new(2.0, 4.0, 8.0));
+    /// assert_eq!(affine.inverse().unwrap().transform_point3(Vector3::new(3.0, 6.0, 11.0)), Vector3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(Affine3::from_scale(Vector3::new(1.0, 0.0, 1.0)).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let matrix = self.matrix.inverse()?;
+        Some(Self::new(matrix, -(matrix * self.translation)))
+    }
+}
+
+impl<T> Copy for TAffine2<T> where T : Copy { }
+impl<T> Clone for TAffine2<T> where T : Copy {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> From<TAffine2<T>> for TMatrix3<T> where T : Num {
+    fn from(value: TAffine2<T>) -> Self {
+        TMatrix3::new(
+            TVector3::from(value.matrix.x),
+            TVector3::from(value.matrix.y),
+            TVector3::new(value.translation.x, value.translation.y, T::one())
+        )
+    }
+}
+
+impl<T> PartialEq for TAffine2<T> where T : PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.matrix == other.matrix &&
+        self.translation == other.translation
+    }
+}
+
+impl<T> Mul<TAffine2<T>> for TAffine2<T> where T : Num {
+    type Output = Self;
+
+    fn mul(self, rhs: TAffine2<T>) -> Self::Output {
+        Self {
+            matrix: self.matrix * rhs.matrix,
+            translation: self.transform_point2(rhs.translation)
+        }
+    }
+}
+
+impl<T> MulAssign<TAffine2<T>> for TAffine2<T> where T : Num {
+    fn mul_assign(&mut self, rhs: TAffine2<T>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T> Debug for TAffine2<T> where T : Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct(format!("TAffine2<{}>", std::any::type_name::<T>()).as_str())
+         .field("matrix", &self.matrix)
+         .field("translation", &self.translation)
+         .finish()
+    }
+}
+
+impl<T> Copy for TAffine3<T> where T : Copy { }
+impl<T> Clone for TAffine3<T> where T : Copy {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> From<TAffine3<T>> for TMatrix4<T> where T : Num {
+    fn from(value: TAffine3<T>) -> Self {
+        TMatrix4::new(
+            TVector4::from(value.matrix.x),
+            TVector4::from(value.matrix.y),
+            TVector4::from(value.matrix.z),
+            TVector4::new(value.translation.x, value.translation.y, value.translation.z, T::one())
+        )
+    }
+}
+
+impl<T> PartialEq for TAffine3<T> where T : PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.matrix == other.matrix &&
+        self.translation == other.translation
+    }
+}
+
+impl<T> Mul<TAffine3<T>> for TAffine3<T> where T : Num {
+    type Output = Self;
+
+    fn mul(self, rhs: TAffine3<T>) -> Self::Output {
+        Self {
+            matrix: self.matrix * rhs.matrix,
+            translation: self.transform_point3(rhs.translation)
+        }
+    }
+}
+
+impl<T> MulAssign<TAffine3<T>> for TAffine3<T> where T : Num {
+    fn mul_assign(&mut self, rhs: TAffine3<T>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T> Debug for TAffine3<T> where T : Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct(format!("TAffine3<{}>", std::any::type_name::<T>()).as_str())
+         .field("matrix", &self.matrix)
+         .field("translation", &self.translation)
+         .finish()
+    }
+}
+
+macro_rules! impl_affine_try_from {
+    (float $($type:ty),+) => {
+        $(
+            // Bottom rows of float matrices built by products or inversion carry round-off, so they are compared with the
+            // same tolerance as in TMatrix4::to_scale_rotation_translation
+            impl_affine_try_from!(@impl $type, |value: $type, expected: $type| (value - expected).abs() <= <$type>::EPSILON.sqrt());
+        )+
+    };
+    (integer $($type:ty),+) => {
+        $(
+            impl_affine_try_from!(@impl $type, |value: $type, expected: $type| value == expected);
+        )+
+    };
+    (@impl $type:ty, $near:expr) => {
+        impl TryFrom<TMatrix3<$type>> for TAffine2<$type> {
+            type Error = NotAffineError;
+
+            fn try_from(value: TMatrix3<$type>) -> std::result::Result<Self, Self::Error> {
+                let near = $near;
+                if !(near(value.x.z, 0 as $type) && near(value.y.z, 0 as $type) && near(value.z.z, 1 as $type)) {
+                    return Err(NotAffineError);
+                }
+
+                Ok(Self {
+                    matrix: TMatrix2 { x: TVector2::from(value.x), y: TVector2::from(value.y) },
+                    translation: TVector2::from(value.z)
+                })
+            }
+        }
+
+        impl TryFrom<TMatrix4<$type>> for TAffine3<$type> {
+            type Error = NotAffineError;
+
+            fn try_from(value: TMatrix4<$type>) -> std::result::Result<Self, Self::Error> {
+                let near = $near;
+                if !(near(value.x.w, 0 as $type) && near(value.y.w, 0 as $type) && near(value.z.w, 0 as $type) && near(value.w.w, 1 as $type)) {
+                    return Err(NotAffineError);
+                }
+
+                Ok(Self {
+                    matrix: TMatrix3::from(value),
+                    translation: TVector3::from(value.w)
+                })
+            }
+        }
+    };
+}
+
+impl_affine_try_from!(float f32, f64);
+impl_affine_try_from!(integer i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Display for NotAffineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "bottom row of matrix is not (0, ..., 0, 1)")
+    }
+}
+
+impl std::error::Error for NotAffineError { }
\ No newline at end of file
@@ -1,16 +1,22 @@
 pub mod num_traits;
 
+/// Components are laid out in memory in order, like [T; N]
+#[repr(C)]
 pub struct TVector2<T> {
     pub x: T,
     pub y: T
 }
 
+/// Components are laid out in memory in order, like [T; N]
+#[repr(C)]
 pub struct TVector3<T> {
     pub x: T,
     pub y: T,
     pub z: T
 }
 
+/// Components are laid out in memory in order, like [T; N]
+#[repr(C)]
 pub struct TVector4<T> {
     pub x: T,
     pub y: T,
@@ -18,9 +24,153 @@ pub struct TVector4<T> {
     pub w: T
 }
 
+/// Members are laid out in memory in order, like [T; 4] holding [w, x, y, z]
+#[repr(C)]
 pub struct TQuaternion<T> {
     pub w: T,
     pub x: T,
     pub y: T,
     pub z: T
-}
\ No newline at end of file
+}
+
+pub struct TMatrix2<T> {
+    pub x: TVector2<T>,
+    pub y: TVector2<T>
+}
+
+pub struct TMatrix3<T> {
+    pub x: TVector3<T>,
+    pub y: TVector3<T>,
+    pub z: TVector3<T>
+}
+
+pub struct TMatrix4<T> {
+    pub x: TVector4<T>,
+    pub y: TVector4<T>,
+    pub z: TVector4<T>,
+    pub w: TVector4<T>
+}
+
+/// Matrix of 2 columns and 3 rows (GLSL mat2x3)
+pub struct TMatrix2x3<T> {
+    pub x: TVector3<T>,
+    pub y: TVector3<T>
+}
+
+/// Matrix of 2 columns and 4 rows (GLSL mat2x4)
+pub struct TMatrix2x4<T> {
+    pub x: TVector4<T>,
+    pub y: TVector4<T>
+}
+
+/// Matrix of 3 columns and 2 rows (GLSL mat3x2)
+pub struct TMatrix3x2<T> {
+    pub x: TVector2<T>,
+    pub y: TVector2<T>,
+    pub z: TVector2<T>
+}
+
+/// Matrix of 3 columns and 4 rows (GLSL mat3x4).
+/// Names follow GLSL (columns x rows), so the row-major "3x4" bone matrix of skinning APIs,
+/// which maps TVector4 to TVector3, is TMatrix4x3 here
+pub struct TMatrix3x4<T> {
+    pub x: TVector4<T>,
+    pub y: TVector4<T>,
+    pub z: TVector4<T>
+}
+
+/// Matrix of 4 columns and 2 rows (GLSL mat4x2)
+pub struct TMatrix4x2<T> {
+    pub x: TVector2<T>,
+    pub y: TVector2<T>,
+    pub z: TVector2<T>,
+    pub w: TVector2<T>
+}
+
+/// Matrix of 4 columns and 3 rows (GLSL mat4x3).
+/// Maps TVector4 to TVector3, e.g. affine bone matrix of skinning, which is called "3x4" in row-major (rows x columns) naming
+pub struct TMatrix4x3<T> {
+    pub x: TVector3<T>,
+    pub y: TVector3<T>,
+    pub z: TVector3<T>,
+    pub w: TVector3<T>
+}
+
+/// Affine transform of 2D space, i.e. 3x3 matrix whose bottom row is (0, 0, 1), stored as linear part and translation
+pub struct TAffine2<T> {
+    pub matrix: TMatrix2<T>,
+    pub translation: TVector2<T>
+}
+
+/// Affine transform of 3D space, i.e. 4x4 matrix whose bottom row is (0, 0, 0, 1), stored as linear part and translation
+pub struct TAffine3<T> {
+    pub matrix: TMatrix3<T>,
+    pub translation: TVector3<T>
+}
+
+/// Components of 4x4 matrix produced by TMatrix4::decompose
+pub struct TDecomposition<T> {
+    pub scale: TVector3<T>,
+    pub rotation: TQuaternion<T>,
+    pub translation: TVector3<T>,
+    /// Shear factors (yz, xz, xy), i.e. y added to z, x added to z and x added to y
+    pub skew: TVector3<T>,
+    /// Bottom row factor, (0, 0, 0, 1) for affine matrix
+    pub perspective: TVector4<T>
+}
+
+/// Order of the axes of Euler angles. Rotations are intrinsic: XYZ rotates around x, then around the new y, then around the new z,
+/// so the resulting rotation equals to Rx * Ry * Rz
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ
+}
+
+/// Reason of failed checked conversion between component types.
+/// Checked conversions succeed only if every component is represented exactly by the target type,
+/// use the `as_*` conversions to round, truncate or saturate instead
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CastErrorKind {
+    /// Component is out of range of the target type
+    Overflow,
+    /// Component is NaN or infinite and the target type is an integer
+    NonFinite,
+    /// Component is in range but would be rounded: an integer the target float type cannot represent exactly,
+    /// a float with fractional part converted to an integer or an f64 which is not exactly an f32
+    PrecisionLoss
+}
+
+/// Error of checked conversion between vectors of different component types
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CastError {
+    /// Index of the first component which failed to convert
+    pub component: usize,
+    pub kind: CastErrorKind
+}
+
+/// Error of parsing vector or quaternion from string such as "(1, 2, 3)" or "1 2 3"
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// Opening or closing parenthesis is missing
+    UnbalancedParentheses,
+    /// Number of components differs from the expected one
+    WrongComponentCount { expected: usize, found: usize },
+    /// Component with the given index is not a valid number
+    InvalidComponent(usize)
+}
+
+/// Error of converting matrix whose bottom row is not (0, ..., 0, 1) into affine transform.
+/// Float bottom rows are compared with tolerance of sqrt(EPSILON) to allow for round-off
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotAffineError;
\ No newline at end of file
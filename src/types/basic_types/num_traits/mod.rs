@@ -1,3 +1,6 @@
+use std::ops::*;
+use std::hash::Hash;
+
 pub trait Zero<T> {
     fn zero() -> T;
 }
@@ -6,146 +9,375 @@ pub trait One<T> {
     fn one() -> T;
 }
 
-impl Zero<f32> for f32 {
-    fn zero() -> f32 {
-        0 as f32
-    }
+/// Primitive number closed under arithmetic operators
+pub trait Num : Zero<Self> + One<Self> + Copy + PartialEq + PartialOrd +
+    Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self> +
+    AddAssign + SubAssign + MulAssign + DivAssign + RemAssign {
+    /// Returns 1 / self if it is representable exactly: None for zero and, as integer division truncates,
+    /// for integers other than 1 and -1
+    fn checked_recip(self) -> Option<Self>;
 }
 
-impl Zero<f64> for f64 {
-    fn zero() -> f64 {
-        0 as f64
-    }
-}
+/// Number which can be negative
+pub trait Signed : Num + Neg<Output = Self> {
+    /// Returns absolute value. Overflows for the minimal value of signed integers
+    fn abs(self) -> Self;
 
-impl Zero<i8> for i8 {
-    fn zero() -> i8 {
-        0 as i8
-    }
-}
+    /// Returns sign: 1 for positive numbers, -1 for negative numbers and 0 for integer zero.
+    /// Float zeroes give 1 or -1 by their sign, NaN gives NaN
+    fn signum(self) -> Self;
 
-impl Zero<i16> for i16 {
-    fn zero() -> i16 {
-        0 as i16
-    }
+    /// Returns true if the number is less than zero
+    fn is_negative(self) -> bool;
 }
 
-impl Zero<i32> for i32 {
-    fn zero() -> i32 {
-        0 as i32
-    }
-}
+/// Number which can not be negative
+pub trait Unsigned : Num { }
 
-impl Zero<i64> for i64 {
-    fn zero() -> i64 {
-        0 as i64
-    }
+/// Number with the smallest and the largest values
+pub trait Bounded {
+    /// Returns the smallest value, which is the most negative finite value for floats
+    fn min_value() -> Self;
+
+    /// Returns the largest value, which is the largest finite value for floats
+    fn max_value() -> Self;
 }
 
-impl Zero<i128> for i128 {
-    fn zero() -> i128 {
-        0 as i128
-    }
+/// Conversion from T which works like `as`: it may truncate, round or saturate
+pub trait Cast<T> {
+    fn cast(value: T) -> Self;
 }
 
-impl Zero<u16> for u16 {
-    fn zero() -> u16 {
-        0 as u16
-    }
+/// Primitive integer
+pub trait Integer : Num + Bounded + Cast<f64> + Cast<usize> + Eq + Ord + Hash {
+    /// Returns quotient of Euclidean division
+    fn div_euclid(self, rhs: Self) -> Self;
+
+    /// Returns non-negative remainder of Euclidean division
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    /// Raises the number to the power of exponent
+    fn pow(self, exponent: u32) -> Self;
 }
 
-impl Zero<u32> for u32 {
-    fn zero() -> u32 {
-        0 as u32
-    }
+/// Primitive floating-point number
+pub trait Float : Signed + Bounded + Cast<f64> + Cast<usize> {
+    const EPSILON: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+    const NAN: Self;
+    const PI: Self;
+    const FRAC_PI_2: Self;
+    const E: Self;
+
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powf(self, exponent: Self) -> Self;
+    fn powi(self, exponent: i32) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn fract(self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn recip(self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn to_degrees(self) -> Self;
+    fn to_radians(self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+
+    /// Converts to usize like `as`: truncates toward zero and saturates, NaN gives 0
+    fn as_usize(self) -> usize;
 }
 
-impl Zero<u64> for u64 {
-    fn zero() -> u64 {
-        0 as u64
-    }
+macro_rules! impl_num {
+    ($($t:ty),+) => {
+        $(
+            impl Zero<$t> for $t {
+                fn zero() -> $t {
+                    0 as $t
+                }
+            }
+
+            impl One<$t> for $t {
+                fn one() -> $t {
+                    1 as $t
+                }
+            }
+
+            impl Bounded for $t {
+                fn min_value() -> $t {
+                    <$t>::MIN
+                }
+
+                fn max_value() -> $t {
+                    <$t>::MAX
+                }
+            }
+        )+
+    };
 }
 
-impl Zero<u128> for u128 {
-    fn zero() -> u128 {
-        0 as u128
-    }
+impl_num!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_num_recip {
+    (float: $($t:ty),+) => {
+        $(
+            impl Num for $t {
+                fn checked_recip(self) -> Option<$t> {
+                    if self == 0.0 { None } else { Some(1.0 / self) }
+                }
+            }
+        )+
+    };
+    (integer: $($t:ty),+) => {
+        $(
+            impl Num for $t {
+                fn checked_recip(self) -> Option<$t> {
+                    if self != 0 && 1 / self != 0 { Some(1 / self) } else { None }
+                }
+            }
+        )+
+    };
 }
 
+impl_num_recip!(float: f32, f64);
+impl_num_recip!(integer: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
 impl Zero<bool> for bool {
     fn zero() -> bool {
         false
     }
 }
 
-impl One<f32> for f32 {
-    fn one() -> f32 {
-        1 as f32
+impl One<bool> for bool {
+    fn one() -> bool {
+        true
     }
 }
 
-impl One<f64> for f64 {
-    fn one() -> f64 {
-        1 as f64
-    }
-}
+macro_rules! impl_signed {
+    ($zero:expr; $($t:ty),+) => {
+        $(
+            impl Signed for $t {
+                fn abs(self) -> $t {
+                    <$t>::abs(self)
+                }
 
-impl One<i8> for i8 {
-    fn one() -> i8 {
-        1 as i8
-    }
-}
+                fn signum(self) -> $t {
+                    <$t>::signum(self)
+                }
 
-impl One<i16> for i16 {
-    fn one() -> i16 {
-        1 as i16
-    }
+                fn is_negative(self) -> bool {
+                    self < $zero
+                }
+            }
+        )+
+    };
 }
 
-impl One<i32> for i32 {
-    fn one() -> i32 {
-        1 as i32
-    }
-}
+impl_signed!(0.0; f32, f64);
+impl_signed!(0; i8, i16, i32, i64, i128, isize);
 
-impl One<i64> for i64 {
-    fn one() -> i64 {
-        1 as i64
-    }
+macro_rules! impl_unsigned {
+    ($($t:ty),+) => {
+        $(
+            impl Unsigned for $t { }
+        )+
+    };
 }
 
-impl One<i128> for i128 {
-    fn one() -> i128 {
-        1 as i128
-    }
-}
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
 
-impl One<u16> for u16 {
-    fn one() -> u16 {
-        1 as u16
-    }
+macro_rules! impl_cast {
+    ([$($t:ty),+] $sources:tt) => {
+        $(
+            impl_cast!(@impl $t, $sources);
+        )+
+    };
+    (@impl $t:ty, [$($source:ty),+]) => {
+        $(
+            impl Cast<$source> for $t {
+                fn cast(value: $source) -> $t {
+                    value as $t
+                }
+            }
+        )+
+    };
 }
 
-impl One<u32> for u32 {
-    fn one() -> u32 {
-        1 as u32
-    }
-}
+impl_cast!([f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize] [f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize]);
 
-impl One<u64> for u64 {
-    fn one() -> u64 {
-        1 as u64
-    }
+macro_rules! impl_integer {
+    ($($t:ty),+) => {
+        $(
+            impl Integer for $t {
+                fn div_euclid(self, rhs: $t) -> $t {
+                    <$t>::div_euclid(self, rhs)
+                }
+
+                fn rem_euclid(self, rhs: $t) -> $t {
+                    <$t>::rem_euclid(self, rhs)
+                }
+
+                fn pow(self, exponent: u32) -> $t {
+                    <$t>::pow(self, exponent)
+                }
+            }
+        )+
+    };
 }
 
-impl One<u128> for u128 {
-    fn one() -> u128 {
-        1 as u128
-    }
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_float {
+    ($($t:ident),+) => {
+        $(
+            impl Float for $t {
+                const EPSILON: $t = <$t>::EPSILON;
+                const INFINITY: $t = <$t>::INFINITY;
+                const NEG_INFINITY: $t = <$t>::NEG_INFINITY;
+                const NAN: $t = <$t>::NAN;
+                const PI: $t = std::$t::consts::PI;
+                const FRAC_PI_2: $t = std::$t::consts::FRAC_PI_2;
+                const E: $t = std::$t::consts::E;
+
+                fn sqrt(self) -> $t {
+                    <$t>::sqrt(self)
+                }
+
+                fn cbrt(self) -> $t {
+                    <$t>::cbrt(self)
+                }
+
+                fn hypot(self, other: $t) -> $t {
+                    <$t>::hypot(self, other)
+                }
+
+                fn sin(self) -> $t {
+                    <$t>::sin(self)
+                }
+
+                fn cos(self) -> $t {
+                    <$t>::cos(self)
+                }
+
+                fn tan(self) -> $t {
+                    <$t>::tan(self)
+                }
+
+                fn sin_cos(self) -> ($t, $t) {
+                    <$t>::sin_cos(self)
+                }
+
+                fn asin(self) -> $t {
+                    <$t>::asin(self)
+                }
+
+                fn acos(self) -> $t {
+                    <$t>::acos(self)
+                }
+
+                fn atan(self) -> $t {
+                    <$t>::atan(self)
+                }
+
+                fn atan2(self, other: $t) -> $t {
+                    <$t>::atan2(self, other)
+                }
+
+                fn exp(self) -> $t {
+                    <$t>::exp(self)
+                }
+
+                fn ln(self) -> $t {
+                    <$t>::ln(self)
+                }
+
+                fn powf(self, exponent: $t) -> $t {
+                    <$t>::powf(self, exponent)
+                }
+
+                fn powi(self, exponent: i32) -> $t {
+                    <$t>::powi(self, exponent)
+                }
+
+                fn floor(self) -> $t {
+                    <$t>::floor(self)
+                }
+
+                fn ceil(self) -> $t {
+                    <$t>::ceil(self)
+                }
+
+                fn round(self) -> $t {
+                    <$t>::round(self)
+                }
+
+                fn trunc(self) -> $t {
+                    <$t>::trunc(self)
+                }
+
+                fn fract(self) -> $t {
+                    <$t>::fract(self)
+                }
+
+                fn copysign(self, sign: $t) -> $t {
+                    <$t>::copysign(self, sign)
+                }
+
+                fn recip(self) -> $t {
+                    <$t>::recip(self)
+                }
+
+                fn mul_add(self, a: $t, b: $t) -> $t {
+                    <$t>::mul_add(self, a, b)
+                }
+
+                fn min(self, other: $t) -> $t {
+                    <$t>::min(self, other)
+                }
+
+                fn max(self, other: $t) -> $t {
+                    <$t>::max(self, other)
+                }
+
+                fn to_degrees(self) -> $t {
+                    <$t>::to_degrees(self)
+                }
+
+                fn to_radians(self) -> $t {
+                    <$t>::to_radians(self)
+                }
+
+                fn is_nan(self) -> bool {
+                    <$t>::is_nan(self)
+                }
+
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
+                }
+
+                fn as_usize(self) -> usize {
+                    self as usize
+                }
+            }
+        )+
+    };
 }
 
-impl One<bool> for bool {
-    fn one() -> bool {
-        true
-    }
-}
\ No newline at end of file
+impl_float!(f32, f64);
\ No newline at end of file
@@ -0,0 +1,2003 @@
+use crate::types::basic_types::{ TVector2, TVector3, TVector4, TQuaternion, TMatrix2, TMatrix3, TMatrix4 };
+use crate::types::basic_types::{ TMatrix2x3, TMatrix2x4, TMatrix3x2, TMatrix3x4, TMatrix4x2, TMatrix4x3, TDecomposition };
+use crate::types::basic_types::num_traits::{ Zero, One, Num, Float };
+
+use std::ops::*;
+use std::fmt::*;
+
+impl<T> TMatrix2<T> where T : Zero<T> + One<T> + Copy {
+    /// Creates TMatrix2&lt;type&gt; whose elements equal to zero
+    /// ```
+    /// use iomath::matrices::Matrix2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let matrix = Matrix2::empty();
+    /// assert_eq!(matrix, Matrix2 { x: Vector2::empty(), y: Vector2::empty() });
+    /// ```
+    pub fn empty() -> Self {
+        Self {
+            x: TVector2::empty(),
+            y: TVector2::empty()
+        }
+    }
+
+    /// Creates TMatrix2&lt;type&gt; whose main diagonal equals to one, other elements to zero
+    /// ```
+    /// use iomath::matrices::Matrix2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let matrix = Matrix2::identity();
+    /// assert_eq!(matrix, Matrix2::new(Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0)));
+    /// ```
+    pub fn identity() -> Self {
+        Self::from_diagonal(TVector2::from_scalar(T::one()))
+    }
+
+    /// Creates TMatrix2&lt;type&gt; from columns x and y
+    /// ```
+    /// use iomath::matrices::Matrix2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    /// assert_eq!(matrix, Matrix2 { x: Vector2::new(1.0, 2.0), y: Vector2::new(3.0, 4.0) });
+    /// ```
+    pub fn new(x: TVector2<T>, y: TVector2<T>) -> Self {
+        Self {
+            x,
+            y
+        }
+    }
+
+    /// Creates TMatrix2&lt;type&gt; whose main diagonal equals to TVector2&lt;type&gt;, other elements to zero
+    /// ```
+    /// use iomath::matrices::Matrix2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let matrix = Matrix2::from_diagonal(Vector2::new(2.0, 3.0));
+    /// assert_eq!(matrix, Matrix2::new(Vector2::new(2.0, 0.0), Vector2::new(0.0, 3.0)));
+    /// ```
+    pub fn from_diagonal(diagonal: TVector2<T>) -> Self {
+        Self {
+            x: TVector2::new(diagonal.x, T::zero()),
+            y: TVector2::new(T::zero(), diagonal.y)
+        }
+    }
+}
+
+impl<T> TMatrix2<T> where T : Copy {
+    /// Returns TMatrix2&lt;type&gt; whose columns are rows of self
+    /// ```
+    /// use iomath::matrices::Matrix2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    /// assert_eq!(matrix.transpose(), Matrix2::new(Vector2::new(1.0, 3.0), Vector2::new(2.0, 4.0)));
+    /// ```
+    pub fn transpose(self) -> Self {
+        Self {
+            x: TVector2 { x: self.x.x, y: self.y.x },
+            y: TVector2 { x: self.x.y, y: self.y.y }
+        }
+    }
+}
+
+impl<T> TMatrix2<T> where T : Sub<Output = T> + Mul<Output = T> + Copy {
+    /// Returns determinant of TMatrix2&lt;type&gt;
+    /// ```
+    /// use iomath::matrices::Matrix2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    /// assert_eq!(matrix.determinant(), -2.0);
+    /// ```
+    pub fn determinant(&self) -> T {
+        self.x.x * self.y.y - self.y.x * self.x.y
+    }
+}
+
+impl<T> TMatrix2<T> where T : Num + Neg<Output = T> {
+    /// Returns inverse of TMatrix2&lt;type&gt; or None if the matrix is singular (its determinant equals to zero).
+    /// Integer matrices are only invertible if their determinant is 1 or -1
+    /// ```
+    /// use iomath::matrices::Matrix2;
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let matrix = Matrix2::new(Vector2::new(2.0, 0.0), Vector2::new(0.0, 4.0));
+    /// assert_eq!(matrix.inverse(), Some(Matrix2::new(Vector2::new(0.5, 0.0), Vector2::new(0.0, 0.25))));
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let inverse_determinant = self.determinant().checked_recip()?;
+        Some(Self {
+            x: TVector2::new(self.y.y * inverse_determinant, -self.x.y * inverse_determinant),
+            y: TVector2::new(-self.y.x * inverse_determinant, self.x.x * inverse_determinant)
+        })
+    }
+}
+
+impl<T> TMatrix3<T> where T : Zero<T> + One<T> + Copy {
+    /// Creates TMatrix3&lt;type&gt; whose elements equal to zero
+    /// ```
+    /// use iomath::matrices::Matrix3;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix3::empty();
+    /// assert_eq!(matrix, Matrix3 { x: Vector3::empty(), y: Vector3::empty(), z: Vector3::empty() });
+    /// ```
+    pub fn empty() -> Self {
+        Self {
+            x: TVector3::empty(),
+            y: TVector3::empty(),
+            z: TVector3::empty()
+        }
+    }
+
+    /// Creates TMatrix3&lt;type&gt; whose main diagonal equals to one, other elements to zero
+    /// ```
+    /// use iomath::matrices::Matrix3;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix3::identity();
+    /// assert_eq!(matrix, Matrix3::from_diagonal(Vector3::new(1.0, 1.0, 1.0)));
+    /// ```
+    pub fn identity() -> Self {
+        Self::from_diagonal(TVector3::from_scalar(T::one()))
+    }
+
+    /// Creates TMatrix3&lt;type&gt; from columns x, y and z
+    /// ```
+    /// use iomath::matrices::Matrix3;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0), Vector3::new(7.0, 8.0, 9.0));
+    /// assert_eq!(matrix.y, Vector3::new(4.0, 5.0, 6.0));
+    /// ```
+    pub fn new(x: TVector3<T>, y: TVector3<T>, z: TVector3<T>) -> Self {
+        Self {
+            x,
+            y,
+            z
+        }
+    }
+
+    /// Creates TMatrix3&lt;type&gt; whose main diagonal equals to TVector3&lt;type&gt;, other elements to zero
+    /// ```
+    /// use iomath::matrices::Matrix3;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix3::from_diagonal(Vector3::new(2.0, 3.0, 4.0));
+    /// assert_eq!(matrix.z, Vector3::new(0.0, 0.0, 4.0));
+    /// ```
+    pub fn from_diagonal(diagonal: TVector3<T>) -> Self {
+        Self {
+            x: TVector3::new(diagonal.x, T::zero(), T::zero()),
+            y: TVector3::new(T::zero(), diagonal.y, T::zero()),
+            z: TVector3::new(T::zero(), T::zero(), diagonal.z)
+        }
+    }
+}
+
+impl<T> TMatrix3<T> where T : Copy {
+    /// Returns TMatrix3&lt;type&gt; whose columns are rows of self
+    /// ```
+    /// use iomath::matrices::Matrix3;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0), Vector3::new(7.0, 8.0, 9.0));
+    /// assert_eq!(matrix.transpose().x, Vector3::new(1.0, 4.0, 7.0));
+    /// ```
+    pub fn transpose(self) -> Self {
+        Self {
+            x: TVector3 { x: self.x.x, y: self.y.x, z: self.z.x },
+            y: TVector3 { x: self.x.y, y: self.y.y, z: self.z.y },
+            z: TVector3 { x: self.x.z, y: self.y.z, z: self.z.z }
+        }
+    }
+}
+
+impl<T> TMatrix3<T> where T : Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    /// Returns determinant of TMatrix3&lt;type&gt;
+    /// ```
+    /// use iomath::matrices::Matrix3;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix3::new(Vector3::new(2.0, 0.0, 1.0), Vector3::new(1.0, 3.0, 0.0), Vector3::new(0.0, 1.0, 4.0));
+    /// assert_eq!(matrix.determinant(), 25.0);
+    /// ```
+    pub fn determinant(&self) -> T {
+        self.x.scalar_triple(self.y, self.z)
+    }
+}
+
+impl<T> TMatrix3<T> where T : Num {
+    /// Returns inverse of TMatrix3&lt;type&gt; or None if the matrix is singular (its determinant equals to zero).
+    /// Integer matrices are only invertible if their determinant is 1 or -1
+    /// ```
+    /// use iomath::matrices::Matrix3;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix3::from_diagonal(Vector3::new(2.0, 4.0, 8.0));
+    /// assert_eq!(matrix.inverse(), Some(Matrix3::from_diagonal(Vector3::new(0.5, 0.25, 0.125))));
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let yz = self.y.cross(self.z);
+        let zx = self.z.cross(self.x);
+        let xy = self.x.cross(self.y);
+
+        let inverse_determinant = self.x.dot(yz).checked_recip()?;
+        Some(Self::new(yz * inverse_determinant, zx * inverse_determinant, xy * inverse_determinant).transpose())
+    }
+}
+
+impl<T> TMatrix4<T> where T : Zero<T> + One<T> + Copy {
+    /// Creates TMatrix4&lt;type&gt; whose elements equal to zero
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let matrix = Matrix4::empty();
+    /// assert_eq!(matrix, Matrix4 { x: Vector4::empty(), y: Vector4::empty(), z: Vector4::empty(), w: Vector4::empty() });
+    /// ```
+    pub fn empty() -> Self {
+        Self {
+            x: TVector4::empty(),
+            y: TVector4::empty(),
+            z: TVector4::empty(),
+            w: TVector4::empty()
+        }
+    }
+
+    /// Creates TMatrix4&lt;type&gt; whose main diagonal equals to one, other elements to zero
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let matrix = Matrix4::identity();
+    /// assert_eq!(matrix, Matrix4::from_diagonal(Vector4::new(1.0, 1.0, 1.0, 1.0)));
+    /// ```
+    pub fn identity() -> Self {
+        Self::from_diagonal(TVector4::from_scalar(T::one()))
+    }
+
+    /// Creates TMatrix4&lt;type&gt; from columns x, y, z and w
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let matrix = Matrix4::new(Vector4::new(1.0, 2.0, 3.0, 4.0), Vector4::new(5.0, 6.0, 7.0, 8.0), Vector4::new(9.0, 10.0, 11.0, 12.0), Vector4::new(13.0, 14.0, 15.0, 16.0));
+    /// assert_eq!(matrix.w, Vector4::new(13.0, 14.0, 15.0, 16.0));
+    /// ```
+    pub fn new(x: TVector4<T>, y: TVector4<T>, z: TVector4<T>, w: TVector4<T>) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            w
+        }
+    }
+
+    /// Creates TMatrix4&lt;type&gt; whose main diagonal equals to TVector4&lt;type&gt;, other elements to zero
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let matrix = Matrix4::from_diagonal(Vector4::new(2.0, 3.0, 4.0, 5.0));
+    /// assert_eq!(matrix.w, Vector4::new(0.0, 0.0, 0.0, 5.0));
+    /// ```
+    pub fn from_diagonal(diagonal: TVector4<T>) -> Self {
+        Self {
+            x: TVector4::new(diagonal.x, T::zero(), T::zero(), T::zero()),
+            y: TVector4::new(T::zero(), diagonal.y, T::zero(), T::zero()),
+            z: TVector4::new(T::zero(), T::zero(), diagonal.z, T::zero()),
+            w: TVector4::new(T::zero(), T::zero(), T::zero(), diagonal.w)
+        }
+    }
+}
+
+impl<T> TMatrix4<T> where T : Copy {
+    /// Returns TMatrix4&lt;type&gt; whose columns are rows of self
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let matrix = Matrix4::new(Vector4::new(1.0, 2.0, 3.0, 4.0), Vector4::new(5.0, 6.0, 7.0, 8.0), Vector4::new(9.0, 10.0, 11.0, 12.0), Vector4::new(13.0, 14.0, 15.0, 16.0));
+    /// assert_eq!(matrix.transpose().x, Vector4::new(1.0, 5.0, 9.0, 13.0));
+    /// ```
+    pub fn transpose(self) -> Self {
+        Self {
+            x: TVector4 { x: self.x.x, y: self.y.x, z: self.z.x, w: self.w.x },
+            y: TVector4 { x: self.x.y, y: self.y.y, z: self.z.y, w: self.w.y },
+            z: TVector4 { x: self.x.z, y: self.y.z, z: self.z.z, w: self.w.z },
+            w: TVector4 { x: self.x.w, y: self.y.w, z: self.z.w, w: self.w.w }
+        }
+    }
+}
+
+impl<T> TMatrix4<T> where T : Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    /// Returns 2x2 minors of the upper (s) and lower (c) halves used by Laplace expansion of determinant and inverse
+    fn laplace_minors(&self) -> ([T; 6], [T; 6]) {
+        let (a, b, m, d) = (self.x, self.y, self.z, self.w);
+        let s = [
+            a.x * b.y - b.x * a.y,
+            a.x * b.z - b.x * a.z,
+            a.x * b.w - b.x * a.w,
+            a.y * b.z - b.y * a.z,
+            a.y * b.w - b.y * a.w,
+            a.z * b.w - b.z * a.w
+        ];
+        let c = [
+            m.x * d.y - d.x * m.y,
+            m.x * d.z - d.x * m.z,
+            m.x * d.w - d.x * m.w,
+            m.y * d.z - d.y * m.z,
+            m.y * d.w - d.y * m.w,
+            m.z * d.w - d.z * m.w
+        ];
+
+        (s, c)
+    }
+
+    /// Returns determinant of TMatrix4&lt;type&gt;
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let matrix = Matrix4::from_diagonal(Vector4::new(1.0, 2.0, 3.0, 4.0));
+    /// assert_eq!(matrix.determinant(), 24.0);
+    /// ```
+    pub fn determinant(&self) -> T {
+        let (s, c) = self.laplace_minors();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+}
+
+impl<T> TMatrix4<T> where T : Num + Neg<Output = T> {
+    /// Returns inverse of TMatrix4&lt;type&gt; or None if the matrix is singular (its determinant equals to zero).
+    /// Integer matrices are only invertible if their determinant is 1 or -1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let matrix = Matrix4::from_diagonal(Vector4::new(2.0, 4.0, 8.0, 1.0));
+    /// assert_eq!(matrix.inverse(), Some(Matrix4::from_diagonal(Vector4::new(0.5, 0.25, 0.125, 1.0))));
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let (s, c) = self.laplace_minors();
+
+        let determinant = s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+        let inverse_determinant = determinant.checked_recip()?;
+
+        let (a, b, m, d) = (self.x, self.y, self.z, self.w);
+        let inverse = Self {
+            x: TVector4 {
+                x: b.y * c[5] - b.z * c[4] + b.w * c[3],
+                y: -a.y * c[5] + a.z * c[4] - a.w * c[3],
+                z: d.y * s[5] - d.z * s[4] + d.w * s[3],
+                w: -m.y * s[5] + m.z * s[4] - m.w * s[3]
+            },
+            y: TVector4 {
+                x: -b.x * c[5] + b.z * c[2] - b.w * c[1],
+                y: a.x * c[5] - a.z * c[2] + a.w * c[1],
+                z: -d.x * s[5] + d.z * s[2] - d.w * s[1],
+                w: m.x * s[5] - m.z * s[2] + m.w * s[1]
+            },
+            z: TVector4 {
+                x: b.x * c[4] - b.y * c[2] + b.w * c[0],
+                y: -a.x * c[4] + a.y * c[2] - a.w * c[0],
+                z: d.x * s[4] - d.y * s[2] + d.w * s[0],
+                w: -m.x * s[4] + m.y * s[2] - m.w * s[0]
+            },
+            w: TVector4 {
+                x: -b.x * c[3] + b.y * c[1] - b.z * c[0],
+                y: a.x * c[3] - a.y * c[1] + a.z * c[0],
+                z: -d.x * s[3] + d.y * s[1] - d.z * s[0],
+                w: m.x * s[3] - m.y * s[1] + m.z * s[0]
+            }
+        };
+
+        Some(inverse * inverse_determinant)
+    }
+}
+
+impl<T> TMatrix4<T> where T : Zero<T> + One<T> + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    /// Creates TMatrix4&lt;type&gt; which rotates the same way as normalized TQuaternion&lt;type&gt;
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let matrix = Matrix4::from_quaternion(Quaternion::new(0.0, 0.0, 0.0, 1.0));
+    /// assert_eq!(matrix * Vector4::new(1.0, 2.0, 3.0, 1.0), Vector4::new(-1.0, -2.0, 3.0, 1.0));
+    /// ```
+    pub fn from_quaternion(quaternion: TQuaternion<T>) -> Self {
+        Self::from(quaternion)
+    }
+}
+
+impl<T> TMatrix4<T> where T : Num {
+    /// Creates TMatrix4&lt;type&gt; which translates points by translation
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(matrix.transform_point3(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(2.0, 3.0, 4.0));
+    /// ```
+    pub fn from_translation(translation: TVector3<T>) -> Self {
+        let mut matrix = Self::identity();
+        matrix.w = TVector4::new(translation.x, translation.y, translation.z, T::one());
+        matrix
+    }
+
+    /// Creates TMatrix4&lt;type&gt; which scales x, y and z by components of scale
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix4::from_scale(Vector3::new(2.0, 3.0, -1.0));
+    /// assert_eq!(matrix.transform_vector3(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(2.0, 3.0, -1.0));
+    /// ```
+    pub fn from_scale(scale: TVector3<T>) -> Self {
+        Self::from_diagonal(TVector4::new(scale.x, scale.y, scale.z, T::one()))
+    }
+
+    /// Creates TMatrix4&lt;type&gt; which shears, e.g. xy is the factor of y added to x:
+    /// x' = x + xy * y + xz * z, y' = y + yx * x + yz * z, z' = z + zx * x + zy * y
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix4::from_shear(2.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    /// assert_eq!(matrix.transform_point3(Vector3::new(1.0, 1.0, 0.0)), Vector3::new(3.0, 1.0, 0.0));
+    /// ```
+    pub fn from_shear(xy: T, xz: T, yx: T, yz: T, zx: T, zy: T) -> Self {
+        Self::new(
+            TVector4::new(T::one(), yx, zx, T::zero()),
+            TVector4::new(xy, T::one(), zy, T::zero()),
+            TVector4::new(xz, yz, T::one(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), T::zero(), T::one())
+        )
+    }
+
+    /// Creates TMatrix4&lt;type&gt; which scales, then rotates by normalized rotation, then translates,
+    /// i.e. translation * rotation * scale
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix4::from_rotation_translation_scale(Quaternion::new(0.0, 0.0, 0.0, 1.0), Vector3::new(1.0, 0.0, 0.0), Vector3::new(2.0, 2.0, 2.0));
+    /// assert_eq!(matrix.transform_point3(Vector3::new(1.0, 0.0, 0.0)), Vector3::new(-1.0, 0.0, 0.0));
+    /// ```
+    pub fn from_rotation_translation_scale(rotation: TQuaternion<T>, translation: TVector3<T>, scale: TVector3<T>) -> Self {
+        let rotation = TMatrix3::from(rotation);
+        Self::new(
+            TVector4::from(rotation.x * scale.x),
+            TVector4::from(rotation.y * scale.y),
+            TVector4::from(rotation.z * scale.z),
+            TVector4::new(translation.x, translation.y, translation.z, T::one())
+        )
+    }
+
+    /// Returns matrix which translates by translation before applying self, i.e. self * from_translation(translation)
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix4::from_scale(Vector3::new(2.0, 2.0, 2.0)).translate(Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(matrix.transform_point3(Vector3::new(0.0, 0.0, 0.0)), Vector3::new(2.0, 0.0, 0.0));
+    /// ```
+    pub fn translate(self, translation: TVector3<T>) -> Self {
+        self * Self::from_translation(translation)
+    }
+
+    /// Returns matrix which scales by scale before applying self, i.e. self * from_scale(scale)
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix4::from_translation(Vector3::new(1.0, 0.0, 0.0)).scale(Vector3::new(2.0, 2.0, 2.0));
+    /// assert_eq!(matrix.transform_point3(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(3.0, 2.0, 2.0));
+    /// ```
+    pub fn scale(self, scale: TVector3<T>) -> Self {
+        self * Self::from_scale(scale)
+    }
+
+    /// Returns matrix which shears before applying self, i.e. self * from_shear(xy, xz, yx, yz, zx, zy)
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix4::from_translation(Vector3::new(0.0, 0.0, 1.0)).shear(0.0, 0.0, 0.0, 0.0, 1.0, 0.0);
+    /// assert_eq!(matrix.transform_point3(Vector3::new(2.0, 0.0, 0.0)), Vector3::new(2.0, 0.0, 3.0));
+    /// ```
+    pub fn shear(self, xy: T, xz: T, yx: T, yz: T, zx: T, zy: T) -> Self {
+        self * Self::from_shear(xy, xz, yx, yz, zx, zy)
+    }
+
+    /// Transforms point, which has implicit w of one, so translation is applied.
+    /// The last row of the matrix is ignored, so the matrix should be affine
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(matrix.transform_point3(Vector3::new(0.0, 0.0, 0.0)), Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn transform_point3(&self, point: TVector3<T>) -> TVector3<T> {
+        TVector3::from(self.x) * point.x + TVector3::from(self.y) * point.y + TVector3::from(self.z) * point.z + TVector3::from(self.w)
+    }
+
+    /// Transforms direction vector, which has implicit w of zero, so translation is not applied
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(matrix.transform_vector3(Vector3::new(0.0, 0.0, 1.0)), Vector3::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn transform_vector3(&self, vector: TVector3<T>) -> TVector3<T> {
+        TVector3::from(self.x) * vector.x + TVector3::from(self.y) * vector.y + TVector3::from(self.z) * vector.z
+    }
+}
+
+// Hadamard's inequality bounds the determinant by the product of column lengths, so their ratio does not depend on scale.
+// Zero product catches columns whose squared length underflows although the determinant does not
+fn is_nearly_singular<T>(linear: TMatrix3<T>) -> bool where T : Float {
+    let determinant = linear.determinant();
+    let bound = linear.x.length() * linear.y.length() * linear.z.length();
+    !determinant.is_finite() || bound == T::zero() || determinant.abs() <= T::EPSILON * bound
+}
+
+impl<T> TMatrix4<T> where T : Float {
+    /// Creates TMatrix4&lt;type&gt; which rotates by angle (in radians) around normalized axis
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix4::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), std::f32::consts::FRAC_PI_2);
+    /// let point = matrix.transform_point3(Vector3::new(1.0, 0.0, 0.0));
+    /// assert!(point.distance(Vector3::new(0.0, 1.0, 0.0)) < 1e-6);
+    /// ```
+    pub fn from_axis_angle(axis: TVector3<T>, angle: T) -> Self {
+        Self::from(TQuaternion::from_axis_angle(axis, angle))
+    }
+
+    /// Returns matrix which rotates by angle (in radians) around normalized axis before applying self,
+    /// i.e. self * from_axis_angle(axis, angle)
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix4::from_translation(Vector3::new(1.0, 0.0, 0.0)).rotate(Vector3::new(0.0, 0.0, 1.0), std::f32::consts::PI);
+    /// let point = matrix.transform_point3(Vector3::new(1.0, 0.0, 0.0));
+    /// assert!(point.distance(Vector3::new(0.0, 0.0, 0.0)) < 1e-6);
+    /// ```
+    pub fn rotate(self, axis: TVector3<T>, angle: T) -> Self {
+        self * Self::from_axis_angle(axis, angle)
+    }
+
+    /// Creates right-handed view matrix for camera at eye looking at center, so that the camera looks along -z
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let view = Matrix4::look_at_rh(Vector3::new(0.0, 0.0, 5.0), Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(view.transform_point3(Vector3::new(0.0, 0.0, 0.0)), Vector3::new(0.0, 0.0, -5.0));
+    /// ```
+    pub fn look_at_rh(eye: TVector3<T>, center: TVector3<T>, up: TVector3<T>) -> Self {
+        let forward = (center - eye).normalize();
+        let side = forward.cross(up).normalize();
+        let up = side.cross(forward);
+        Self::new(
+            TVector4::new(side.x, up.x, -forward.x, T::zero()),
+            TVector4::new(side.y, up.y, -forward.y, T::zero()),
+            TVector4::new(side.z, up.z, -forward.z, T::zero()),
+            TVector4::new(-side.dot(eye), -up.dot(eye), forward.dot(eye), T::one())
+        )
+    }
+
+    /// Creates left-handed view matrix for camera at eye looking at center, so that the camera looks along +z
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let view = Matrix4::look_at_lh(Vector3::new(0.0, 0.0, 5.0), Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(view.transform_point3(Vector3::new(0.0, 0.0, 0.0)), Vector3::new(0.0, 0.0, 5.0));
+    /// ```
+    pub fn look_at_lh(eye: TVector3<T>, center: TVector3<T>, up: TVector3<T>) -> Self {
+        let forward = (center - eye).normalize();
+        let side = up.cross(forward).normalize();
+        let up = forward.cross(side);
+        Self::new(
+            TVector4::new(side.x, up.x, forward.x, T::zero()),
+            TVector4::new(side.y, up.y, forward.y, T::zero()),
+            TVector4::new(side.z, up.z, forward.z, T::zero()),
+            TVector4::new(-side.dot(eye), -up.dot(eye), -forward.dot(eye), T::one())
+        )
+    }
+
+    /// Creates right-handed perspective projection with vertical field of view fov_y (in radians),
+    /// mapping depth between near and far onto OpenGL clip depth -1..1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::perspective_rh_gl(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+    /// let near = projection * Vector4::new(0.0, 0.0, -1.0, 1.0);
+    /// let far = projection * Vector4::new(0.0, 0.0, -10.0, 1.0);
+    /// assert!((near.z / near.w - -1.0).abs() < 1e-6);
+    /// assert!((far.z / far.w - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn perspective_rh_gl(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        let focal = T::one() / (fov_y / T::cast(2.0)).tan();
+        Self::new(
+            TVector4::new(focal / aspect, T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), focal, T::zero(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), -(far + near) / (far - near), -T::one()),
+            TVector4::new(T::zero(), T::zero(), -(T::cast(2.0) * far * near) / (far - near), T::zero())
+        )
+    }
+
+    /// Creates right-handed perspective projection with vertical field of view fov_y (in radians),
+    /// mapping depth between near and far onto Vulkan, DirectX and wgpu clip depth 0..1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::perspective_rh_zo(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+    /// let near = projection * Vector4::new(0.0, 0.0, -1.0, 1.0);
+    /// let far = projection * Vector4::new(0.0, 0.0, -10.0, 1.0);
+    /// assert!((near.z / near.w - 0.0).abs() < 1e-6);
+    /// assert!((far.z / far.w - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn perspective_rh_zo(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        let focal = T::one() / (fov_y / T::cast(2.0)).tan();
+        Self::new(
+            TVector4::new(focal / aspect, T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), focal, T::zero(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), far / (near - far), -T::one()),
+            TVector4::new(T::zero(), T::zero(), -(far * near) / (far - near), T::zero())
+        )
+    }
+
+    /// Creates left-handed perspective projection with vertical field of view fov_y (in radians),
+    /// mapping depth between near and far onto OpenGL clip depth -1..1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::perspective_lh_gl(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+    /// let near = projection * Vector4::new(0.0, 0.0, 1.0, 1.0);
+    /// let far = projection * Vector4::new(0.0, 0.0, 10.0, 1.0);
+    /// assert!((near.z / near.w - -1.0).abs() < 1e-6);
+    /// assert!((far.z / far.w - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn perspective_lh_gl(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        let focal = T::one() / (fov_y / T::cast(2.0)).tan();
+        Self::new(
+            TVector4::new(focal / aspect, T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), focal, T::zero(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), (far + near) / (far - near), T::one()),
+            TVector4::new(T::zero(), T::zero(), -(T::cast(2.0) * far * near) / (far - near), T::zero())
+        )
+    }
+
+    /// Creates left-handed perspective projection with vertical field of view fov_y (in radians),
+    /// mapping depth between near and far onto Vulkan, DirectX and wgpu clip depth 0..1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::perspective_lh_zo(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+    /// let near = projection * Vector4::new(0.0, 0.0, 1.0, 1.0);
+    /// let far = projection * Vector4::new(0.0, 0.0, 10.0, 1.0);
+    /// assert!((near.z / near.w - 0.0).abs() < 1e-6);
+    /// assert!((far.z / far.w - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn perspective_lh_zo(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        let focal = T::one() / (fov_y / T::cast(2.0)).tan();
+        Self::new(
+            TVector4::new(focal / aspect, T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), focal, T::zero(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), far / (far - near), T::one()),
+            TVector4::new(T::zero(), T::zero(), -(far * near) / (far - near), T::zero())
+        )
+    }
+
+    /// Creates right-handed perspective projection without far plane, mapping depth from near to infinity onto OpenGL clip depth -1..1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::perspective_infinite_rh_gl(std::f32::consts::FRAC_PI_2, 1.0, 1.0);
+    /// let near = projection * Vector4::new(0.0, 0.0, -1.0, 1.0);
+    /// let far = projection * Vector4::new(0.0, 0.0, -1.0e6, 1.0);
+    /// assert!((near.z / near.w - -1.0).abs() < 1e-6);
+    /// assert!((far.z / far.w - 1.0).abs() < 1e-5);
+    /// ```
+    pub fn perspective_infinite_rh_gl(fov_y: T, aspect: T, near: T) -> Self {
+        let focal = T::one() / (fov_y / T::cast(2.0)).tan();
+        Self::new(
+            TVector4::new(focal / aspect, T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), focal, T::zero(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), -T::one(), -T::one()),
+            TVector4::new(T::zero(), T::zero(), -T::cast(2.0) * near, T::zero())
+        )
+    }
+
+    /// Creates right-handed perspective projection without far plane, mapping depth from near to infinity onto clip depth 0..1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::perspective_infinite_rh_zo(std::f32::consts::FRAC_PI_2, 1.0, 1.0);
+    /// let near = projection * Vector4::new(0.0, 0.0, -1.0, 1.0);
+    /// let far = projection * Vector4::new(0.0, 0.0, -1.0e6, 1.0);
+    /// assert!((near.z / near.w - 0.0).abs() < 1e-6);
+    /// assert!((far.z / far.w - 1.0).abs() < 1e-5);
+    /// ```
+    pub fn perspective_infinite_rh_zo(fov_y: T, aspect: T, near: T) -> Self {
+        let focal = T::one() / (fov_y / T::cast(2.0)).tan();
+        Self::new(
+            TVector4::new(focal / aspect, T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), focal, T::zero(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), -T::one(), -T::one()),
+            TVector4::new(T::zero(), T::zero(), -near, T::zero())
+        )
+    }
+
+    /// Creates left-handed perspective projection without far plane, mapping depth from near to infinity onto OpenGL clip depth -1..1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::perspective_infinite_lh_gl(std::f32::consts::FRAC_PI_2, 1.0, 1.0);
+    /// let near = projection * Vector4::new(0.0, 0.0, 1.0, 1.0);
+    /// let far = projection * Vector4::new(0.0, 0.0, 1.0e6, 1.0);
+    /// assert!((near.z / near.w - -1.0).abs() < 1e-6);
+    /// assert!((far.z / far.w - 1.0).abs() < 1e-5);
+    /// ```
+    pub fn perspective_infinite_lh_gl(fov_y: T, aspect: T, near: T) -> Self {
+        let focal = T::one() / (fov_y / T::cast(2.0)).tan();
+        Self::new(
+            TVector4::new(focal / aspect, T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), focal, T::zero(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), T::one(), T::one()),
+            TVector4::new(T::zero(), T::zero(), -T::cast(2.0) * near, T::zero())
+        )
+    }
+
+    /// Creates left-handed perspective projection without far plane, mapping depth from near to infinity onto clip depth 0..1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::perspective_infinite_lh_zo(std::f32::consts::FRAC_PI_2, 1.0, 1.0);
+    /// let near = projection * Vector4::new(0.0, 0.0, 1.0, 1.0);
+    /// let far = projection * Vector4::new(0.0, 0.0, 1.0e6, 1.0);
+    /// assert!((near.z / near.w - 0.0).abs() < 1e-6);
+    /// assert!((far.z / far.w - 1.0).abs() < 1e-5);
+    /// ```
+    pub fn perspective_infinite_lh_zo(fov_y: T, aspect: T, near: T) -> Self {
+        let focal = T::one() / (fov_y / T::cast(2.0)).tan();
+        Self::new(
+            TVector4::new(focal / aspect, T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), focal, T::zero(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), T::one(), T::one()),
+            TVector4::new(T::zero(), T::zero(), -near, T::zero())
+        )
+    }
+
+    /// Creates right-handed perspective projection without far plane and with reversed depth,
+    /// mapping depth from near to infinity onto clip depth 1..0 for better floating-point depth precision
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::perspective_infinite_reverse_rh(std::f32::consts::FRAC_PI_2, 1.0, 1.0);
+    /// let near = projection * Vector4::new(0.0, 0.0, -1.0, 1.0);
+    /// let far = projection * Vector4::new(0.0, 0.0, -1.0e6, 1.0);
+    /// assert!((near.z / near.w - 1.0).abs() < 1e-6);
+    /// assert!((far.z / far.w - 0.0).abs() < 1e-5);
+    /// ```
+    pub fn perspective_infinite_reverse_rh(fov_y: T, aspect: T, near: T) -> Self {
+        let focal = T::one() / (fov_y / T::cast(2.0)).tan();
+        Self::new(
+            TVector4::new(focal / aspect, T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), focal, T::zero(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), T::zero(), -T::one()),
+            TVector4::new(T::zero(), T::zero(), near, T::zero())
+        )
+    }
+
+    /// Creates left-handed perspective projection without far plane and with reversed depth,
+    /// mapping depth from near to infinity onto clip depth 1..0 for better floating-point depth precision
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::perspective_infinite_reverse_lh(std::f32::consts::FRAC_PI_2, 1.0, 1.0);
+    /// let near = projection * Vector4::new(0.0, 0.0, 1.0, 1.0);
+    /// let far = projection * Vector4::new(0.0, 0.0, 1.0e6, 1.0);
+    /// assert!((near.z / near.w - 1.0).abs() < 1e-6);
+    /// assert!((far.z / far.w - 0.0).abs() < 1e-5);
+    /// ```
+    pub fn perspective_infinite_reverse_lh(fov_y: T, aspect: T, near: T) -> Self {
+        let focal = T::one() / (fov_y / T::cast(2.0)).tan();
+        Self::new(
+            TVector4::new(focal / aspect, T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), focal, T::zero(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), T::zero(), T::one()),
+            TVector4::new(T::zero(), T::zero(), near, T::zero())
+        )
+    }
+
+    /// Creates right-handed orthographic projection of the given box, mapping depth between near and far onto OpenGL clip depth -1..1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::orthographic_rh_gl(-2.0, 2.0, -1.0, 1.0, 1.0, 3.0);
+    /// assert_eq!(projection * Vector4::new(2.0, -1.0, -1.0, 1.0), Vector4::new(1.0, -1.0, -1.0, 1.0));
+    /// assert_eq!(projection * Vector4::new(-2.0, 1.0, -3.0, 1.0), Vector4::new(-1.0, 1.0, 1.0, 1.0));
+    /// ```
+    pub fn orthographic_rh_gl(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let two = T::cast(2.0);
+        Self::new(
+            TVector4::new(two / (right - left), T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), two / (top - bottom), T::zero(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), -two / (far - near), T::zero()),
+            TVector4::new(-(right + left) / (right - left), -(top + bottom) / (top - bottom), -(far + near) / (far - near), T::one())
+        )
+    }
+
+    /// Creates right-handed orthographic projection of the given box, mapping depth between near and far onto clip depth 0..1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::orthographic_rh_zo(-2.0, 2.0, -1.0, 1.0, 1.0, 3.0);
+    /// assert_eq!(projection * Vector4::new(2.0, -1.0, -1.0, 1.0), Vector4::new(1.0, -1.0, 0.0, 1.0));
+    /// assert_eq!(projection * Vector4::new(-2.0, 1.0, -3.0, 1.0), Vector4::new(-1.0, 1.0, 1.0, 1.0));
+    /// ```
+    pub fn orthographic_rh_zo(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let two = T::cast(2.0);
+        Self::new(
+            TVector4::new(two / (right - left), T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), two / (top - bottom), T::zero(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), -T::one() / (far - near), T::zero()),
+            TVector4::new(-(right + left) / (right - left), -(top + bottom) / (top - bottom), -near / (far - near), T::one())
+        )
+    }
+
+    /// Creates left-handed orthographic projection of the given box, mapping depth between near and far onto OpenGL clip depth -1..1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::orthographic_lh_gl(-2.0, 2.0, -1.0, 1.0, 1.0, 3.0);
+    /// assert_eq!(projection * Vector4::new(2.0, -1.0, 1.0, 1.0), Vector4::new(1.0, -1.0, -1.0, 1.0));
+    /// assert_eq!(projection * Vector4::new(-2.0, 1.0, 3.0, 1.0), Vector4::new(-1.0, 1.0, 1.0, 1.0));
+    /// ```
+    pub fn orthographic_lh_gl(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let two = T::cast(2.0);
+        Self::new(
+            TVector4::new(two / (right - left), T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), two / (top - bottom), T::zero(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), two / (far - near), T::zero()),
+            TVector4::new(-(right + left) / (right - left), -(top + bottom) / (top - bottom), -(far + near) / (far - near), T::one())
+        )
+    }
+
+    /// Creates left-handed orthographic projection of the given box, mapping depth between near and far onto clip depth 0..1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::orthographic_lh_zo(-2.0, 2.0, -1.0, 1.0, 1.0, 3.0);
+    /// assert_eq!(projection * Vector4::new(2.0, -1.0, 1.0, 1.0), Vector4::new(1.0, -1.0, 0.0, 1.0));
+    /// assert_eq!(projection * Vector4::new(-2.0, 1.0, 3.0, 1.0), Vector4::new(-1.0, 1.0, 1.0, 1.0));
+    /// ```
+    pub fn orthographic_lh_zo(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let two = T::cast(2.0);
+        Self::new(
+            TVector4::new(two / (right - left), T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), two / (top - bottom), T::zero(), T::zero()),
+            TVector4::new(T::zero(), T::zero(), T::one() / (far - near), T::zero()),
+            TVector4::new(-(right + left) / (right - left), -(top + bottom) / (top - bottom), -near / (far - near), T::one())
+        )
+    }
+
+    /// Creates right-handed perspective projection of the view frustum whose near plane spans left..right and bottom..top,
+    /// mapping depth between near and far onto OpenGL clip depth -1..1 like glFrustum
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let projection = Matrix4::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0);
+    /// assert_eq!(projection, Matrix4::perspective_rh_gl(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0));
+    /// ```
+    pub fn frustum(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let two = T::cast(2.0);
+        Self::new(
+            TVector4::new(two * near / (right - left), T::zero(), T::zero(), T::zero()),
+            TVector4::new(T::zero(), two * near / (top - bottom), T::zero(), T::zero()),
+            TVector4::new((right + left) / (right - left), (top + bottom) / (top - bottom), -(far + near) / (far - near), -T::one()),
+            TVector4::new(T::zero(), T::zero(), -(two * far * near) / (far - near), T::zero())
+        )
+    }
+
+    /// Maps object from world to window coordinates, where self is the combined projection * view * model matrix
+    /// producing OpenGL clip depth -1..1 and viewport is (x, y, width, height). Window depth is in 0..1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::{ Vector3, Vector4 };
+    /// 
+    /// let projection = Matrix4::orthographic_rh_gl(-1.0, 1.0, -1.0, 1.0, 0.0, 2.0);
+    /// let window = projection.project_gl(Vector3::new(0.0, 1.0, -1.0), Vector4::new(0.0, 0.0, 800.0, 600.0));
+    /// assert_eq!(window, Vector3::new(400.0, 600.0, 0.5));
+    /// ```
+    pub fn project_gl(&self, object: TVector3<T>, viewport: TVector4<T>) -> TVector3<T> {
+        let half = T::cast(0.5);
+        let ndc = self.project_ndc(object);
+        Self::ndc_to_window(TVector3::new(ndc.x, ndc.y, ndc.z * half + half), viewport)
+    }
+
+    /// Maps object from world to window coordinates, where self is the combined projection * view * model matrix
+    /// producing clip depth 0..1 and viewport is (x, y, width, height). Window depth is in 0..1
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::{ Vector3, Vector4 };
+    /// 
+    /// let projection = Matrix4::orthographic_rh_zo(-1.0, 1.0, -1.0, 1.0, 0.0, 2.0);
+    /// let window = projection.project_zo(Vector3::new(-1.0, 0.0, -1.0), Vector4::new(0.0, 0.0, 800.0, 600.0));
+    /// assert_eq!(window, Vector3::new(0.0, 300.0, 0.5));
+    /// ```
+    pub fn project_zo(&self, object: TVector3<T>, viewport: TVector4<T>) -> TVector3<T> {
+        Self::ndc_to_window(self.project_ndc(object), viewport)
+    }
+
+    /// Maps window coordinates back to world, inverting project_gl.
+    /// Returns None if self is not invertible or window lies on the plane through the eye
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::{ Vector3, Vector4 };
+    /// 
+    /// let projection = Matrix4::orthographic_rh_gl(-1.0, 1.0, -1.0, 1.0, 0.0, 2.0);
+    /// let object = projection.unproject_gl(Vector3::new(400.0, 600.0, 0.5), Vector4::new(0.0, 0.0, 800.0, 600.0));
+    /// assert_eq!(object, Some(Vector3::new(0.0, 1.0, -1.0)));
+    /// ```
+    pub fn unproject_gl(&self, window: TVector3<T>, viewport: TVector4<T>) -> Option<TVector3<T>> {
+        let two = T::cast(2.0);
+        let ndc = Self::window_to_ndc(window, viewport);
+        self.unproject_ndc(TVector3::new(ndc.x, ndc.y, ndc.z * two - T::one()))
+    }
+
+    /// Maps window coordinates back to world, inverting project_zo.
+    /// Returns None if self is not invertible or window lies on the plane through the eye
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::vectors::{ Vector3, Vector4 };
+    /// 
+    /// let projection = Matrix4::orthographic_rh_zo(-1.0, 1.0, -1.0, 1.0, 0.0, 2.0);
+    /// let object = projection.unproject_zo(Vector3::new(0.0, 300.0, 0.5), Vector4::new(0.0, 0.0, 800.0, 600.0));
+    /// assert_eq!(object, Some(Vector3::new(-1.0, 0.0, -1.0)));
+    /// ```
+    pub fn unproject_zo(&self, window: TVector3<T>, viewport: TVector4<T>) -> Option<TVector3<T>> {
+        self.unproject_ndc(Self::window_to_ndc(window, viewport))
+    }
+
+    /// Decomposes affine matrix into (scale, rotation, translation) such that
+    /// from_rotation_translation_scale(rotation, translation, scale) rebuilds it.
+    /// Mirroring (negative determinant) is reported as negative x scale.
+    /// Returns None if the matrix is projective, degenerate or sheared
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let matrix = Matrix4::from_rotation_translation_scale(Quaternion::identity(), Vector3::new(1.0, 2.0, 3.0), Vector3::new(-2.0, 3.0, 4.0));
+    /// let (scale, rotation, translation) = matrix.to_scale_rotation_translation().unwrap();
+    /// assert_eq!(scale, Vector3::new(-2.0, 3.0, 4.0));
+    /// assert_eq!(rotation, Quaternion::identity());
+    /// assert_eq!(translation, Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(Matrix4::from_shear(1.0, 0.0, 0.0, 0.0, 0.0, 0.0).to_scale_rotation_translation(), None);
+    /// ```
+    pub fn to_scale_rotation_translation(&self) -> Option<(TVector3<T>, TQuaternion<T>, TVector3<T>)> {
+        let tolerance = T::EPSILON.sqrt();
+        let bottom = TVector4::new(self.x.w, self.y.w, self.z.w, self.w.w);
+        if (bottom - TVector4::new(T::zero(), T::zero(), T::zero(), T::one())).abs().max_element() > tolerance {
+            return None;
+        }
+
+        let linear = TMatrix3::from(*self);
+        if is_nearly_singular(linear) {
+            return None;
+        }
+
+        let sign = linear.determinant().signum();
+        let scale = TVector3::new(linear.x.length() * sign, linear.y.length(), linear.z.length());
+        let rotation = TMatrix3::new(linear.x / scale.x, linear.y / scale.y, linear.z / scale.z);
+        let skew = TVector3::new(rotation.x.dot(rotation.y), rotation.x.dot(rotation.z), rotation.y.dot(rotation.z));
+        if skew.iter().any(|&component| !component.is_finite() || component.abs() > tolerance) {
+            return None;
+        }
+
+        Some((scale, TQuaternion::from_rotation_matrix(rotation), TVector3::from(self.w)))
+    }
+
+    /// Decomposes matrix like GLM decompose into scale, rotation, translation, skew and perspective,
+    /// so that translation * rotation * skew * scale rebuilds the affine part.
+    /// Mirroring is reported as negation of all scale components. Returns None if the matrix is degenerate
+    /// ```
+    /// use iomath::matrices::Matrix4;
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::{ Vector3, Vector4 };
+    /// 
+    /// let matrix = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)).scale(Vector3::new(2.0, 2.0, 2.0)).shear(0.5, 0.0, 0.0, 0.0, 0.0, 0.0);
+    /// let decomposition = matrix.decompose().unwrap();
+    /// assert_eq!(decomposition.scale, Vector3::new(2.0, 2.0, 2.0));
+    /// assert_eq!(decomposition.rotation, Quaternion::identity());
+    /// assert_eq!(decomposition.translation, Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(decomposition.skew, Vector3::new(0.0, 0.0, 0.5));
+    /// assert_eq!(decomposition.perspective, Vector4::new(0.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub fn decompose(&self) -> Option<TDecomposition<T>> {
+        if self.w.w.abs() <= T::EPSILON {
+            return None;
+        }
+
+        let mut local = *self / self.w.w;
+        let mut projection = local;
+        projection.x.w = T::zero();
+        projection.y.w = T::zero();
+        projection.z.w = T::zero();
+        projection.w.w = T::one();
+        if is_nearly_singular(TMatrix3::from(projection)) {
+            return None;
+        }
+
+        let mut perspective = TVector4::new(T::zero(), T::zero(), T::zero(), T::one());
+        if local.x.w != T::zero() || local.y.w != T::zero() || local.z.w != T::zero() {
+            let bottom = TVector4::new(local.x.w, local.y.w, local.z.w, local.w.w);
+            perspective = projection.inverse()?.transpose() * bottom;
+            local.x.w = T::zero();
+            local.y.w = T::zero();
+            local.z.w = T::zero();
+            local.w.w = T::one();
+        }
+
+        let translation = TVector3::from(local.w);
+        let mut rows = [TVector3::from(local.x), TVector3::from(local.y), TVector3::from(local.z)];
+        let mut scale = TVector3::empty();
+        let mut skew = TVector3::empty();
+
+        scale.x = rows[0].length();
+        rows[0] /= scale.x;
+
+        skew.z = rows[0].dot(rows[1]);
+        rows[1] -= rows[0] * skew.z;
+        scale.y = rows[1].length();
+        rows[1] /= scale.y;
+        skew.z /= scale.y;
+
+        skew.y = rows[0].dot(rows[2]);
+        rows[2] -= rows[0] * skew.y;
+        skew.x = rows[1].dot(rows[2]);
+        rows[2] -= rows[1] * skew.x;
+        scale.z = rows[2].length();
+        rows[2] /= scale.z;
+        skew.y /= scale.z;
+        skew.x /= scale.z;
+
+        if rows[0].dot(rows[1].cross(rows[2])) < T::zero() {
+            scale = -scale;
+            rows = [-rows[0], -rows[1], -rows[2]];
+        }
+
+        let rotation = TQuaternion::from_rotation_matrix(TMatrix3::new(rows[0], rows[1], rows[2]));
+        Some(TDecomposition { scale, rotation, translation, skew, perspective })
+    }
+
+    fn project_ndc(&self, object: TVector3<T>) -> TVector3<T> {
+        let clip = *self * TVector4::new(object.x, object.y, object.z, T::one());
+        TVector3::from(clip) / clip.w
+    }
+
+    fn unproject_ndc(&self, ndc: TVector3<T>) -> Option<TVector3<T>> {
+        let object = self.inverse()? * TVector4::new(ndc.x, ndc.y, ndc.z, T::one());
+        if object.w == T::zero() {
+            return None;
+        }
+        Some(TVector3::from(object) / object.w)
+    }
+
+    fn ndc_to_window(ndc: TVector3<T>, viewport: TVector4<T>) -> TVector3<T> {
+        let half = T::cast(0.5);
+        TVector3::new(
+            (ndc.x * half + half) * viewport.z + viewport.x,
+            (ndc.y * half + half) * viewport.w + viewport.y,
+            ndc.z
+        )
+    }
+
+    fn window_to_ndc(window: TVector3<T>, viewport: TVector4<T>) -> TVector3<T> {
+        let two = T::cast(2.0);
+        TVector3::new(
+            (window.x - viewport.x) / viewport.z * two - T::one(),
+            (window.y - viewport.y) / viewport.w * two - T::one(),
+            window.z
+        )
+    }
+}
+
+impl<T> Copy for TMatrix2<T> where T : Copy { }
+impl<T> Clone for TMatrix2<T> where T : Copy {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Index<usize> for TMatrix2<T> {
+    type Output = TVector2<T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("index out of bounds: TMatrix2 has 2 columns but the index is {}", index)
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for TMatrix2<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("index out of bounds: TMatrix2 has 2 columns but the index is {}", index)
+        }
+    }
+}
+
+impl<T> PartialEq for TMatrix2<T> where T : PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x &&
+        self.y == other.y
+    }
+}
+
+impl<T> Add<TMatrix2<T>> for TMatrix2<T> where T : Add<Output = T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y
+        }
+    }
+}
+
+impl<T> AddAssign<TMatrix2<T>> for TMatrix2<T> where T : AddAssign {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T> Sub<TMatrix2<T>> for TMatrix2<T> where T : Sub<Output = T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y
+        }
+    }
+}
+
+impl<T> SubAssign<TMatrix2<T>> for TMatrix2<T> where T : SubAssign {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T> Mul<T> for TMatrix2<T> where T : Mul<Output = T> + Copy {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Self {
+            x: self.x * scalar,
+            y: self.y * scalar
+        }
+    }
+}
+
+impl<T> Mul<TVector2<T>> for TMatrix2<T> where T : Add<Output = T> + Mul<Output = T> + Copy {
+    type Output = TVector2<T>;
+
+    fn mul(self, vector: TVector2<T>) -> Self::Output {
+        self.x * vector.x + self.y * vector.y
+    }
+}
+
+impl<T> Mul<TMatrix2<T>> for TVector2<T> where T : Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    type Output = Self;
+
+    fn mul(self, matrix: TMatrix2<T>) -> Self::Output {
+        Self {
+            x: self.dot(matrix.x),
+            y: self.dot(matrix.y)
+        }
+    }
+}
+
+impl<T> Mul<TMatrix2<T>> for TMatrix2<T> where T : Add<Output = T> + Mul<Output = T> + Copy {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            x: self * other.x,
+            y: self * other.y
+        }
+    }
+}
+
+impl<T> MulAssign<T> for TMatrix2<T> where T : MulAssign + Copy {
+    fn mul_assign(&mut self, scalar: T) {
+        self.x *= scalar;
+        self.y *= scalar;
+    }
+}
+
+impl<T> MulAssign<TMatrix2<T>> for TMatrix2<T> where T : Add<Output = T> + Mul<Output = T> + Copy {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T> Div<T> for TMatrix2<T> where T : Div<Output = T> + Copy {
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self::Output {
+        Self {
+            x: self.x / scalar,
+            y: self.y / scalar
+        }
+    }
+}
+
+impl<T> DivAssign<T> for TMatrix2<T> where T : DivAssign + Copy {
+    fn div_assign(&mut self, scalar: T) {
+        self.x /= scalar;
+        self.y /= scalar;
+    }
+}
+
+impl<T> Neg for TMatrix2<T> where T : Neg<Output = T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y
+        }
+    }
+}
+
+impl<T> Debug for TMatrix2<T> where T : Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct(format!("TMatrix2<{}>", std::any::type_name::<T>()).as_str())
+         .field("x", &self.x)
+         .field("y", &self.y)
+         .finish()
+    }
+}
+
+impl<T> Copy for TMatrix3<T> where T : Copy { }
+impl<T> Clone for TMatrix3<T> where T : Copy {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> From<TMatrix4<T>> for TMatrix3<T> {
+    fn from(matrix: TMatrix4<T>) -> Self {
+        Self {
+            x: TVector3::from(matrix.x),
+            y: TVector3::from(matrix.y),
+            z: TVector3::from(matrix.z)
+        }
+    }
+}
+
+impl<T> From<TQuaternion<T>> for TMatrix3<T> where T : One<T> + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    fn from(quat: TQuaternion<T>) -> Self {
+        let (w, x, y, z) = (quat.w, quat.x, quat.y, quat.z);
+        let (x2, y2, z2) = (x + x, y + y, z + z);
+        let (xx, yy, zz) = (x * x2, y * y2, z * z2);
+        let (xy, xz, yz) = (x * y2, x * z2, y * z2);
+        let (wx, wy, wz) = (w * x2, w * y2, w * z2);
+
+        Self {
+            x: TVector3 { x: T::one() - (yy + zz), y: xy + wz, z: xz - wy },
+            y: TVector3 { x: xy - wz, y: T::one() - (xx + zz), z: yz + wx },
+            z: TVector3 { x: xz + wy, y: yz - wx, z: T::one() - (xx + yy) }
+        }
+    }
+}
+
+impl<T> Index<usize> for TMatrix3<T> {
+    type Output = TVector3<T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("index out of bounds: TMatrix3 has 3 columns but the index is {}", index)
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for TMatrix3<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("index out of bounds: TMatrix3 has 3 columns but the index is {}", index)
+        }
+    }
+}
+
+impl<T> PartialEq for TMatrix3<T> where T : PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x &&
+        self.y == other.y &&
+        self.z == other.z
+    }
+}
+
+impl<T> Add<TMatrix3<T>> for TMatrix3<T> where T : Add<Output = T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z
+        }
+    }
+}
+
+impl<T> AddAssign<TMatrix3<T>> for TMatrix3<T> where T : AddAssign {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl<T> Sub<TMatrix3<T>> for TMatrix3<T> where T : Sub<Output = T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z
+        }
+    }
+}
+
+impl<T> SubAssign<TMatrix3<T>> for TMatrix3<T> where T : SubAssign {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+impl<T> Mul<T> for TMatrix3<T> where T : Mul<Output = T> + Copy {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Self {
+            x: self.x * scalar,
+            y: self.y * scalar,
+            z: self.z * scalar
+        }
+    }
+}
+
+impl<T> Mul<TVector3<T>> for TMatrix3<T> where T : Add<Output = T> + Mul<Output = T> + Copy {
+    type Output = TVector3<T>;
+
+    fn mul(self, vector: TVector3<T>) -> Self::Output {
+        self.x * vector.x + self.y * vector.y + self.z * vector.z
+    }
+}
+
+impl<T> Mul<TMatrix3<T>> for TVector3<T> where T : Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    type Output = Self;
+
+    fn mul(self, matrix: TMatrix3<T>) -> Self::Output {
+        Self {
+            x: self.dot(matrix.x),
+            y: self.dot(matrix.y),
+            z: self.dot(matrix.z)
+        }
+    }
+}
+
+impl<T> Mul<TMatrix3<T>> for TMatrix3<T> where T : Add<Output = T> + Mul<Output = T> + Copy {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z
+        }
+    }
+}
+
+impl<T> MulAssign<T> for TMatrix3<T> where T : MulAssign + Copy {
+    fn mul_assign(&mut self, scalar: T) {
+        self.x *= scalar;
+        self.y *= scalar;
+        self.z *= scalar;
+    }
+}
+
+impl<T> MulAssign<TMatrix3<T>> for TMatrix3<T> where T : Add<Output = T> + Mul<Output = T> + Copy {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T> Div<T> for TMatrix3<T> where T : Div<Output = T> + Copy {
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self::Output {
+        Self {
+            x: self.x / scalar,
+            y: self.y / scalar,
+            z: self.z / scalar
+        }
+    }
+}
+
+impl<T> DivAssign<T> for TMatrix3<T> where T : DivAssign + Copy {
+    fn div_assign(&mut self, scalar: T) {
+        self.x /= scalar;
+        self.y /= scalar;
+        self.z /= scalar;
+    }
+}
+
+impl<T> Neg for TMatrix3<T> where T : Neg<Output = T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z
+        }
+    }
+}
+
+impl<T> Debug for TMatrix3<T> where T : Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct(format!("TMatrix3<{}>", std::any::type_name::<T>()).as_str())
+         .field("x", &self.x)
+         .field("y", &self.y)
+         .field("z", &self.z)
+         .finish()
+    }
+}
+
+impl<T> Copy for TMatrix4<T> where T : Copy { }
+impl<T> Clone for TMatrix4<T> where T : Copy {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TDecomposition<T> where T : Copy { }
+impl<T> Clone for TDecomposition<T> where T : Copy {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> From<TMatrix3<T>> for TMatrix4<T> where T : Zero<T> + One<T> {
+    fn from(matrix: TMatrix3<T>) -> Self {
+        Self {
+            x: TVector4::from(matrix.x),
+            y: TVector4::from(matrix.y),
+            z: TVector4::from(matrix.z),
+            w: TVector4 { x: T::zero(), y: T::zero(), z: T::zero(), w: T::one() }
+        }
+    }
+}
+
+impl<T> From<TQuaternion<T>> for TMatrix4<T> where T : Zero<T> + One<T> + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    fn from(quat: TQuaternion<T>) -> Self {
+        Self::from(TMatrix3::from(quat))
+    }
+}
+
+impl<T> Index<usize> for TMatrix4<T> {
+    type Output = TVector4<T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("index out of bounds: TMatrix4 has 4 columns but the index is {}", index)
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for TMatrix4<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("index out of bounds: TMatrix4 has 4 columns but the index is {}", index)
+        }
+    }
+}
+
+impl<T> PartialEq for TMatrix4<T> where T : PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x &&
+        self.y == other.y &&
+        self.z == other.z &&
+        self.w == other.w
+    }
+}
+
+impl<T> PartialEq for TDecomposition<T> where T : PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.scale == other.scale &&
+        self.rotation == other.rotation &&
+        self.translation == other.translation &&
+        self.skew == other.skew &&
+        self.perspective == other.perspective
+    }
+}
+
+impl<T> Add<TMatrix4<T>> for TMatrix4<T> where T : Add<Output = T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w + other.w
+        }
+    }
+}
+
+impl<T> AddAssign<TMatrix4<T>> for TMatrix4<T> where T : AddAssign {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+        self.w += other.w;
+    }
+}
+
+impl<T> Sub<TMatrix4<T>> for TMatrix4<T> where T : Sub<Output = T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+            w: self.w - other.w
+        }
+    }
+}
+
+impl<T> SubAssign<TMatrix4<T>> for TMatrix4<T> where T : SubAssign {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+        self.w -= other.w;
+    }
+}
+
+impl<T> Mul<T> for TMatrix4<T> where T : Mul<Output = T> + Copy {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Self {
+            x: self.x * scalar,
+            y: self.y * scalar,
+            z: self.z * scalar,
+            w: self.w * scalar
+        }
+    }
+}
+
+impl<T> Mul<TVector4<T>> for TMatrix4<T> where T : Add<Output = T> + Mul<Output = T> + Copy {
+    type Output = TVector4<T>;
+
+    fn mul(self, vector: TVector4<T>) -> Self::Output {
+        self.x * vector.x + self.y * vector.y + self.z * vector.z + self.w * vector.w
+    }
+}
+
+impl<T> Mul<TMatrix4<T>> for TVector4<T> where T : Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    type Output = Self;
+
+    fn mul(self, matrix: TMatrix4<T>) -> Self::Output {
+        Self {
+            x: self.dot(matrix.x),
+            y: self.dot(matrix.y),
+            z: self.dot(matrix.z),
+            w: self.dot(matrix.w)
+        }
+    }
+}
+
+impl<T> Mul<TMatrix4<T>> for TMatrix4<T> where T : Add<Output = T> + Mul<Output = T> + Copy {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+            w: self * other.w
+        }
+    }
+}
+
+impl<T> MulAssign<T> for TMatrix4<T> where T : MulAssign + Copy {
+    fn mul_assign(&mut self, scalar: T) {
+        self.x *= scalar;
+        self.y *= scalar;
+        self.z *= scalar;
+        self.w *= scalar;
+    }
+}
+
+impl<T> MulAssign<TMatrix4<T>> for TMatrix4<T> where T : Add<Output = T> + Mul<Output = T> + Copy {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T> Div<T> for TMatrix4<T> where T : Div<Output = T> + Copy {
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self::Output {
+        Self {
+            x: self.x / scalar,
+            y: self.y / scalar,
+            z: self.z / scalar,
+            w: self.w / scalar
+        }
+    }
+}
+
+impl<T> DivAssign<T> for TMatrix4<T> where T : DivAssign + Copy {
+    fn div_assign(&mut self, scalar: T) {
+        self.x /= scalar;
+        self.y /= scalar;
+        self.z /= scalar;
+        self.w /= scalar;
+    }
+}
+
+impl<T> Neg for TMatrix4<T> where T : Neg<Output = T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w
+        }
+    }
+}
+
+impl<T> Debug for TMatrix4<T> where T : Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct(format!("TMatrix4<{}>", std::any::type_name::<T>()).as_str())
+         .field("x", &self.x)
+         .field("y", &self.y)
+         .field("z", &self.z)
+         .field("w", &self.w)
+         .finish()
+    }
+}
+
+impl<T> Debug for TDecomposition<T> where T : Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct(format!("TDecomposition<{}>", std::any::type_name::<T>()).as_str())
+         .field("scale", &self.scale)
+         .field("rotation", &self.rotation)
+         .field("translation", &self.translation)
+         .field("skew", &self.skew)
+         .field("perspective", &self.perspective)
+         .finish()
+    }
+}
+
+macro_rules! matrix_row {
+    ($matrix:expr, $row:ident, $component:ident, { $($index:literal: $field:ident),+ }) => {
+        $row { $($field: $matrix.$field.$component),+ }
+    };
+}
+
+macro_rules! matrix_mul_vector {
+    ($matrix:expr, $vector:expr, { $first_index:literal: $first:ident $(, $index:literal: $field:ident)* }) => {
+        $matrix.$first * $vector.$first $(+ $matrix.$field * $vector.$field)*
+    };
+}
+
+macro_rules! impl_non_square_matrix {
+    ($matrix:ident, $transposed:ident, $column:ident, $row:ident, $columns:literal, $diagonal:literal, $fields:tt, { $($component:ident),+ }, [$alias:ident, $transposed_alias:ident, $column_alias:ident]) => {
+        impl_non_square_matrix!(@impl $matrix, $transposed, $column, $row, $columns, $diagonal, $fields, $fields, { $($component),+ }, [$alias, $transposed_alias, $column_alias]);
+    };
+    (@impl $matrix:ident, $transposed:ident, $column:ident, $row:ident, $columns:literal, $diagonal:literal, $fields:tt, { $($index:literal: $field:ident),+ }, { $($component:ident),+ }, [$alias:ident, $transposed_alias:ident, $column_alias:ident]) => {
+        impl<T> $matrix<T> where T : Zero<T> + One<T> + Copy {
+            #[doc = concat!("Creates ", stringify!($matrix), "&lt;type&gt; whose elements equal to zero")]
+            /// ```
+            #[doc = concat!("use iomath::matrices::", stringify!($alias), ";")]
+            #[doc = concat!("use iomath::vectors::", stringify!($column_alias), ";")]
+            /// 
+            #[doc = concat!("let matrix = ", stringify!($alias), "::empty();")]
+            #[doc = concat!("assert!((0..", $columns, ").all(|index| matrix[index] == ", stringify!($column_alias), "::empty()));")]
+            /// ```
+            pub fn empty() -> Self {
+                Self {
+                    $($field: $column::empty()),+
+                }
+            }
+
+            #[doc = concat!("Creates ", stringify!($matrix), "&lt;type&gt; whose main diagonal equals to one, other elements to zero")]
+            /// ```
+            #[doc = concat!("use iomath::matrices::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let matrix = ", stringify!($alias), "::identity();")]
+            /// assert_eq!(matrix[1][1], 1.0);
+            /// assert_eq!(matrix[0][1], 0.0);
+            /// ```
+            pub fn identity() -> Self {
+                let mut matrix = Self::empty();
+                for index in 0..$diagonal {
+                    matrix[index][index] = T::one();
+                }
+
+                matrix
+            }
+
+            #[doc = concat!("Creates ", stringify!($matrix), "&lt;type&gt; from columns")]
+            /// ```
+            #[doc = concat!("use iomath::matrices::", stringify!($alias), ";")]
+            #[doc = concat!("use iomath::vectors::", stringify!($column_alias), ";")]
+            /// 
+            #[doc = concat!("let matrix = ", stringify!($alias), "::new(", $(stringify!($column_alias), "::from_scalar(", $index, ".0), ",)+ ");")]
+            #[doc = concat!("assert_eq!(matrix[1], ", stringify!($column_alias), "::from_scalar(1.0));")]
+            /// ```
+            pub fn new($($field: $column<T>),+) -> Self {
+                Self {
+                    $($field),+
+                }
+            }
+        }
+
+        impl<T> $matrix<T> where T : Copy {
+            /// Returns matrix whose columns are rows of self
+            /// ```
+            #[doc = concat!("use iomath::matrices::{ ", stringify!($alias), ", ", stringify!($transposed_alias), " };")]
+            #[doc = concat!("use iomath::vectors::", stringify!($column_alias), ";")]
+            /// 
+            #[doc = concat!("let matrix = ", stringify!($alias), "::new(", $(stringify!($column_alias), "::from_scalar(", $index, ".0), ",)+ ");")]
+            /// assert_eq!(matrix.transpose()[0][1], matrix[1][0]);
+            #[doc = concat!("assert_eq!(", stringify!($alias), "::identity().transpose(), ", stringify!($transposed_alias), "::identity());")]
+            /// ```
+            pub fn transpose(self) -> $transposed<T> {
+                $transposed {
+                    $($component: matrix_row!(self, $row, $component, $fields)),+
+                }
+            }
+        }
+
+        impl<T> Copy for $matrix<T> where T : Copy { }
+        impl<T> Clone for $matrix<T> where T : Copy {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T> Index<usize> for $matrix<T> {
+            type Output = $column<T>;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                match index {
+                    $($index => &self.$field,)+
+                    _ => panic!(concat!("index out of bounds: ", stringify!($matrix), " has ", $columns, " columns but the index is {}"), index)
+                }
+            }
+        }
+
+        impl<T> IndexMut<usize> for $matrix<T> {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                match index {
+                    $($index => &mut self.$field,)+
+                    _ => panic!(concat!("index out of bounds: ", stringify!($matrix), " has ", $columns, " columns but the index is {}"), index)
+                }
+            }
+        }
+
+        impl<T> PartialEq for $matrix<T> where T : PartialEq {
+            fn eq(&self, other: &Self) -> bool {
+                $(self.$field == other.$field)&&+
+            }
+        }
+
+        impl<T> Add<$matrix<T>> for $matrix<T> where T : Add<Output = T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self::Output {
+                Self {
+                    $($field: self.$field + other.$field),+
+                }
+            }
+        }
+
+        impl<T> AddAssign<$matrix<T>> for $matrix<T> where T : AddAssign {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$field += other.$field;)+
+            }
+        }
+
+        impl<T> Sub<$matrix<T>> for $matrix<T> where T : Sub<Output = T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self::Output {
+                Self {
+                    $($field: self.$field - other.$field),+
+                }
+            }
+        }
+
+        impl<T> SubAssign<$matrix<T>> for $matrix<T> where T : SubAssign {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$field -= other.$field;)+
+            }
+        }
+
+        impl<T> Mul<T> for $matrix<T> where T : Mul<Output = T> + Copy {
+            type Output = Self;
+
+            fn mul(self, scalar: T) -> Self::Output {
+                Self {
+                    $($field: self.$field * scalar),+
+                }
+            }
+        }
+
+        impl<T> Mul<$row<T>> for $matrix<T> where T : Add<Output = T> + Mul<Output = T> + Copy {
+            type Output = $column<T>;
+
+            fn mul(self, vector: $row<T>) -> Self::Output {
+                matrix_mul_vector!(self, vector, $fields)
+            }
+        }
+
+        impl<T> Mul<$matrix<T>> for $column<T> where T : Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+            type Output = $row<T>;
+
+            fn mul(self, matrix: $matrix<T>) -> Self::Output {
+                $row {
+                    $($field: self.dot(matrix.$field)),+
+                }
+            }
+        }
+
+        impl<T> MulAssign<T> for $matrix<T> where T : MulAssign + Copy {
+            fn mul_assign(&mut self, scalar: T) {
+                $(self.$field *= scalar;)+
+            }
+        }
+
+        impl<T> Div<T> for $matrix<T> where T : Div<Output = T> + Copy {
+            type Output = Self;
+
+            fn div(self, scalar: T) -> Self::Output {
+                Self {
+                    $($field: self.$field / scalar),+
+                }
+            }
+        }
+
+        impl<T> DivAssign<T> for $matrix<T> where T : DivAssign + Copy {
+            fn div_assign(&mut self, scalar: T) {
+                $(self.$field /= scalar;)+
+            }
+        }
+
+        impl<T> Neg for $matrix<T> where T : Neg<Output = T> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self {
+                    $($field: -self.$field),+
+                }
+            }
+        }
+
+        impl<T> Debug for $matrix<T> where T : Debug {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                f.debug_struct(format!(concat!(stringify!($matrix), "<{}>"), std::any::type_name::<T>()).as_str())
+                 $(.field(stringify!($field), &self.$field))+
+                 .finish()
+            }
+        }
+    };
+}
+
+impl_non_square_matrix!(TMatrix2x3, TMatrix3x2, TVector3, TVector2, 2, 2, { 0: x, 1: y }, { x, y, z }, [Matrix2x3, Matrix3x2, Vector3]);
+impl_non_square_matrix!(TMatrix2x4, TMatrix4x2, TVector4, TVector2, 2, 2, { 0: x, 1: y }, { x, y, z, w }, [Matrix2x4, Matrix4x2, Vector4]);
+impl_non_square_matrix!(TMatrix3x2, TMatrix2x3, TVector2, TVector3, 3, 2, { 0: x, 1: y, 2: z }, { x, y }, [Matrix3x2, Matrix2x3, Vector2]);
+impl_non_square_matrix!(TMatrix3x4, TMatrix4x3, TVector4, TVector3, 3, 3, { 0: x, 1: y, 2: z }, { x, y, z, w }, [Matrix3x4, Matrix4x3, Vector4]);
+impl_non_square_matrix!(TMatrix4x2, TMatrix2x4, TVector2, TVector4, 4, 2, { 0: x, 1: y, 2: z, 3: w }, { x, y }, [Matrix4x2, Matrix2x4, Vector2]);
+impl_non_square_matrix!(TMatrix4x3, TMatrix3x4, TVector3, TVector4, 4, 3, { 0: x, 1: y, 2: z, 3: w }, { x, y, z }, [Matrix4x3, Matrix3x4, Vector3]);
+
+macro_rules! impl_matrix_mul {
+    ($lhs:ident, $rhs:ident, $output:ident, { $($field:ident),+ }) => {
+        impl<T> Mul<$rhs<T>> for $lhs<T> where T : Add<Output = T> + Mul<Output = T> + Copy {
+            type Output = $output<T>;
+
+            fn mul(self, other: $rhs<T>) -> Self::Output {
+                $output {
+                    $($field: self * other.$field),+
+                }
+            }
+        }
+    };
+}
+
+impl_matrix_mul!(TMatrix2, TMatrix3x2, TMatrix3x2, { x, y, z });
+impl_matrix_mul!(TMatrix2, TMatrix4x2, TMatrix4x2, { x, y, z, w });
+impl_matrix_mul!(TMatrix2x3, TMatrix2, TMatrix2x3, { x, y });
+impl_matrix_mul!(TMatrix2x3, TMatrix3x2, TMatrix3, { x, y, z });
+impl_matrix_mul!(TMatrix2x3, TMatrix4x2, TMatrix4x3, { x, y, z, w });
+impl_matrix_mul!(TMatrix2x4, TMatrix2, TMatrix2x4, { x, y });
+impl_matrix_mul!(TMatrix2x4, TMatrix3x2, TMatrix3x4, { x, y, z });
+impl_matrix_mul!(TMatrix2x4, TMatrix4x2, TMatrix4, { x, y, z, w });
+impl_matrix_mul!(TMatrix3x2, TMatrix2x3, TMatrix2, { x, y });
+impl_matrix_mul!(TMatrix3x2, TMatrix3, TMatrix3x2, { x, y, z });
+impl_matrix_mul!(TMatrix3x2, TMatrix4x3, TMatrix4x2, { x, y, z, w });
+impl_matrix_mul!(TMatrix3, TMatrix2x3, TMatrix2x3, { x, y });
+impl_matrix_mul!(TMatrix3, TMatrix4x3, TMatrix4x3, { x, y, z, w });
+impl_matrix_mul!(TMatrix3x4, TMatrix2x3, TMatrix2x4, { x, y });
+impl_matrix_mul!(TMatrix3x4, TMatrix3, TMatrix3x4, { x, y, z });
+impl_matrix_mul!(TMatrix3x4, TMatrix4x3, TMatrix4, { x, y, z, w });
+impl_matrix_mul!(TMatrix4x2, TMatrix2x4, TMatrix2, { x, y });
+impl_matrix_mul!(TMatrix4x2, TMatrix3x4, TMatrix3x2, { x, y, z });
+impl_matrix_mul!(TMatrix4x2, TMatrix4, TMatrix4x2, { x, y, z, w });
+impl_matrix_mul!(TMatrix4x3, TMatrix2x4, TMatrix2x3, { x, y });
+impl_matrix_mul!(TMatrix4x3, TMatrix3x4, TMatrix3, { x, y, z });
+impl_matrix_mul!(TMatrix4x3, TMatrix4, TMatrix4x3, { x, y, z, w });
+impl_matrix_mul!(TMatrix4, TMatrix2x4, TMatrix2x4, { x, y });
+impl_matrix_mul!(TMatrix4, TMatrix3x4, TMatrix3x4, { x, y, z });
\ No newline at end of file
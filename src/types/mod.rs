@@ -1,4 +1,7 @@
 mod vectors;
 mod quaternion;
+mod matrices;
+mod affine;
+mod swizzles;
 
 pub mod basic_types;
\ No newline at end of file
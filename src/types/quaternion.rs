@@ -1,8 +1,11 @@
-use crate::types::basic_types::TQuaternion;
+use crate::types::basic_types::{ TVector3, TMatrix3, TQuaternion, EulerOrder, ParseError };
+use crate::types::vectors::parse_components;
 use crate::types::basic_types::num_traits::*;
 
 use std::ops::*;
 use std::fmt::*;
+use std::hash::{ Hash, Hasher };
+use std::str::FromStr;
 
 impl<T> TQuaternion<T> where T : Zero<T> + One<T> {
     /// Creates TQuaternion whose members are (w: 1, x: 0, y: 0, z: 0)
@@ -22,6 +25,651 @@ impl<T> TQuaternion<T> where T : Zero<T> + One<T> {
     }
 }
 
+impl<T> TQuaternion<T> {
+    /// Creates TQuaternion&lt;type&gt; with w, x, y and z
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let quaternion = Quaternion::new(0.5, -1.0, 2.0, 3.5);
+    /// assert_eq!(quaternion, Quaternion { w: 0.5, x: -1.0, y: 2.0, z: 3.5 });
+    /// ```
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Self {
+            w,
+            x,
+            y,
+            z
+        }
+    }
+
+    /// Returns reference to members as array in order [w, x, y, z]
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let quaternion = Quaternion::new(0.5, -1.0, 2.0, 3.5);
+    /// assert_eq!(quaternion.as_array(), &[0.5, -1.0, 2.0, 3.5]);
+    /// ```
+    pub fn as_array(&self) -> &[T; 4] {
+        // TQuaternion is #[repr(C)] and all of its fields are T, so it has the layout of [T; 4]
+        unsafe { &*(self as *const Self as *const [T; 4]) }
+    }
+
+    /// Returns mutable reference to members as array in order [w, x, y, z]
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let mut quaternion = Quaternion::new(0.5, -1.0, 2.0, 3.5);
+    /// quaternion.as_mut_array()[0] = 1.0;
+    /// assert_eq!(quaternion, Quaternion::new(1.0, -1.0, 2.0, 3.5));
+    /// ```
+    pub fn as_mut_array(&mut self) -> &mut [T; 4] {
+        // TQuaternion is #[repr(C)] and all of its fields are T, so it has the layout of [T; 4]
+        unsafe { &mut *(self as *mut Self as *mut [T; 4]) }
+    }
+}
+
+impl<T> TQuaternion<T> where T : Copy {
+    /// Creates TQuaternion&lt;type&gt; from the first 4 elements of slice in order [w, x, y, z]. Panics if the slice is shorter
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let quaternion = Quaternion::from_slice(&[0.5, -1.0, 2.0, 3.5, 7.0]);
+    /// assert_eq!(quaternion, Quaternion::new(0.5, -1.0, 2.0, 3.5));
+    /// ```
+    pub fn from_slice(slice: &[T]) -> Self {
+        Self::new(slice[0], slice[1], slice[2], slice[3])
+    }
+
+    /// Writes members to the first 4 elements of slice in order [w, x, y, z]. Panics if the slice is shorter
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let mut buffer = [0.0; 5];
+    /// Quaternion::new(0.5, -1.0, 2.0, 3.5).write_to_slice(&mut buffer[1..]);
+    /// assert_eq!(buffer, [0.0, 0.5, -1.0, 2.0, 3.5]);
+    /// ```
+    pub fn write_to_slice(self, slice: &mut [T]) {
+        slice[..4].copy_from_slice(self.as_array());
+    }
+}
+
+impl<T> TQuaternion<T> where T : Add<Output = T> + Mul<Output = T> + Copy {
+    /// Returns dot product of two TQuaternion&lt;type&gt;
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let dot = Quaternion::new(1.0, 2.0, 3.0, 4.0).dot(Quaternion::new(0.5, -1.0, 0.0, 2.0));
+    /// assert_eq!(dot, 6.5);
+    /// ```
+    pub fn dot(self, other: Self) -> T {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns squared norm of TQuaternion&lt;type&gt;
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let norm_squared = Quaternion::new(1.0, 2.0, 2.0, 4.0).norm_squared();
+    /// assert_eq!(norm_squared, 25.0);
+    /// ```
+    pub fn norm_squared(self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T> TQuaternion<T> where T : Neg<Output = T> {
+    /// Returns conjugate of TQuaternion&lt;type&gt;, whose vector part is negated
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let conjugate = Quaternion::new(1.0, 2.0, -3.0, 4.0).conjugate();
+    /// assert_eq!(conjugate, Quaternion::new(1.0, -2.0, 3.0, -4.0));
+    /// ```
+    pub fn conjugate(self) -> Self {
+        Self {
+            w: self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z
+        }
+    }
+}
+
+impl<T> TQuaternion<T> where T : Add<Output = T> + Mul<Output = T> + Div<Output = T> + Neg<Output = T> + Copy {
+    /// Returns multiplicative inverse of TQuaternion&lt;type&gt;, so that q * q.inverse() is identity.
+    /// Components of the result are NaN (or division panics for integers) if the quaternion is zero
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let inverse = Quaternion::new(1.0, 1.0, 1.0, 1.0).inverse();
+    /// assert_eq!(inverse, Quaternion::new(0.25, -0.25, -0.25, -0.25));
+    /// ```
+    pub fn inverse(self) -> Self {
+        self.conjugate() / self.norm_squared()
+    }
+}
+
+impl EulerOrder {
+    /// Returns indices of the axes in the order of rotation
+    fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2]
+        }
+    }
+}
+
+impl<T> TQuaternion<T> where T : Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    /// Returns TVector3&lt;type&gt; rotated by normalized TQuaternion&lt;type&gt;.
+    /// Uses v + w * t + u × t, where u is vector part of quaternion and t = 2 * (u × v), which is cheaper than q * v * q⁻¹
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let quaternion = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(quaternion.rotate_vector(Vector3::new(1.0, 2.0, 3.0)), Vector3::new(-1.0, -2.0, 3.0));
+    /// ```
+    pub fn rotate_vector(self, vector: TVector3<T>) -> TVector3<T> {
+        let vector_part = TVector3::from(self);
+        let cross = vector_part.cross(vector);
+        let t = cross + cross;
+        vector + t * self.w + vector_part.cross(t)
+    }
+
+    /// Rotates every TVector3&lt;type&gt; of slice in place by normalized TQuaternion&lt;type&gt;
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let mut vectors = [Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0)];
+    /// Quaternion::new(0.0, 1.0, 0.0, 0.0).rotate_slice(&mut vectors);
+    /// assert_eq!(vectors, [Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, -1.0, 0.0)]);
+    /// ```
+    pub fn rotate_slice(self, vectors: &mut [TVector3<T>]) {
+        for vector in vectors.iter_mut() {
+            *vector = self.rotate_vector(*vector);
+        }
+    }
+}
+
+impl<T> TQuaternion<T> where T : Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    /// Returns component-wise linear interpolation between two TQuaternion&lt;type&gt;, the result is not normalized
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let lerp = Quaternion::new(1.0, 0.0, 0.0, 0.0).lerp(Quaternion::new(0.0, 0.0, 0.0, 1.0), 0.25);
+    /// assert_eq!(lerp, Quaternion::new(0.75, 0.0, 0.0, 0.25));
+    /// ```
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl<T> TQuaternion<T> where T : Float {
+    /// Returns norm (length) of quaternion
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// assert_eq!(Quaternion::new(1.0, 2.0, 2.0, 4.0).norm(), 5.0);
+    /// ```
+    pub fn norm(self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    /// Returns quaternion with the same direction and norm of one.
+    /// Components of the result are NaN if the quaternion's norm is zero
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let quaternion = Quaternion::new(0.0, 3.0, 0.0, 4.0).normalize();
+    /// assert_eq!(quaternion, Quaternion::new(0.0, 0.6, 0.0, 0.8));
+    /// ```
+    pub fn normalize(self) -> Self {
+        self / self.norm()
+    }
+
+    /// Creates quaternion which rotates by angle (in radians) around normalized axis
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let quaternion = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), std::f32::consts::FRAC_PI_2);
+    /// assert!(quaternion.rotate_vector(Vector3::new(1.0, 0.0, 0.0)).distance(Vector3::new(0.0, 1.0, 0.0)) < 1e-6);
+    /// ```
+    pub fn from_axis_angle(axis: TVector3<T>, angle: T) -> Self {
+        let (sin, cos) = (angle * T::cast(0.5)).sin_cos();
+        Self {
+            w: cos,
+            x: axis.x * sin,
+            y: axis.y * sin,
+            z: axis.z * sin
+        }
+    }
+
+    /// Creates quaternion which rotates by angle (in radians) around x axis
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let quaternion = Quaternion::from_rotation_x(std::f32::consts::FRAC_PI_2);
+    /// assert!(quaternion.rotate_vector(Vector3::new(0.0, 1.0, 0.0)).distance(Vector3::new(0.0, 0.0, 1.0)) < 1e-6);
+    /// ```
+    pub fn from_rotation_x(angle: T) -> Self {
+        Self::from_axis_angle(TVector3::new(T::one(), T::zero(), T::zero()), angle)
+    }
+
+    /// Creates quaternion which rotates by angle (in radians) around y axis
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let quaternion = Quaternion::from_rotation_y(std::f32::consts::FRAC_PI_2);
+    /// assert!(quaternion.rotate_vector(Vector3::new(0.0, 0.0, 1.0)).distance(Vector3::new(1.0, 0.0, 0.0)) < 1e-6);
+    /// ```
+    pub fn from_rotation_y(angle: T) -> Self {
+        Self::from_axis_angle(TVector3::new(T::zero(), T::one(), T::zero()), angle)
+    }
+
+    /// Creates quaternion which rotates by angle (in radians) around z axis
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let quaternion = Quaternion::from_rotation_z(std::f32::consts::FRAC_PI_2);
+    /// assert!(quaternion.rotate_vector(Vector3::new(1.0, 0.0, 0.0)).distance(Vector3::new(0.0, 1.0, 0.0)) < 1e-6);
+    /// ```
+    pub fn from_rotation_z(angle: T) -> Self {
+        Self::from_axis_angle(TVector3::new(T::zero(), T::zero(), T::one()), angle)
+    }
+
+    fn from_rotation_axis(axis: usize, angle: T) -> Self {
+        match axis {
+            0 => Self::from_rotation_x(angle),
+            1 => Self::from_rotation_y(angle),
+            _ => Self::from_rotation_z(angle)
+        }
+    }
+
+    /// Creates quaternion from intrinsic Euler angles (in radians) applied in the given order,
+    /// e.g. for EulerOrder::ZXY the result is Rz(first) * Rx(second) * Ry(third)
+    /// ```
+    /// use iomath::quaternions::{ Quaternion, EulerOrder };
+    /// 
+    /// let quaternion = Quaternion::from_euler(EulerOrder::ZXY, 0.5, 0.25, -1.0);
+    /// assert_eq!(quaternion, Quaternion::from_rotation_z(0.5) * Quaternion::from_rotation_x(0.25) * Quaternion::from_rotation_y(-1.0));
+    /// ```
+    pub fn from_euler(order: EulerOrder, first: T, second: T, third: T) -> Self {
+        let [i, j, k] = order.axes();
+        Self::from_rotation_axis(i, first) * Self::from_rotation_axis(j, second) * Self::from_rotation_axis(k, third)
+    }
+
+    /// Creates the shortest rotation which takes normalized vector from onto normalized vector to.
+    /// If the vectors are opposite the rotation is by pi around an arbitrary axis orthogonal to from
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let quaternion = Quaternion::from_rotation_arc(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.6, 0.8));
+    /// assert!(quaternion.rotate_vector(Vector3::new(1.0, 0.0, 0.0)).distance(Vector3::new(0.0, 0.6, 0.8)) < 1e-6);
+    /// ```
+    pub fn from_rotation_arc(from: TVector3<T>, to: TVector3<T>) -> Self {
+        let one_minus_epsilon = T::one() - T::cast(2.0) * T::EPSILON;
+        let dot = from.dot(to);
+        if dot > one_minus_epsilon {
+            return Self::identity();
+        }
+
+        if dot < -one_minus_epsilon {
+            let helper = if from.x.abs() < T::cast(0.9) { TVector3::new(T::one(), T::zero(), T::zero()) } else { TVector3::new(T::zero(), T::one(), T::zero()) };
+            return Self::from_axis_angle(from.cross(helper).normalize(), T::PI);
+        }
+
+        let cross = from.cross(to);
+        Self::new(T::one() + dot, cross.x, cross.y, cross.z).normalize()
+    }
+
+    /// Returns normalized axis and angle (in radians, from 0 to 2pi) of rotation described by normalized quaternion.
+    /// Axis is x if there is no rotation
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let (axis, angle) = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 1.5).to_axis_angle();
+    /// assert!(axis.distance(Vector3::new(0.0, 1.0, 0.0)) < 1e-6);
+    /// assert!((angle - 1.5).abs() < 1e-6);
+    /// ```
+    pub fn to_axis_angle(self) -> (TVector3<T>, T) {
+        let vector = TVector3::from(self);
+        let sin = vector.length();
+        if sin > T::zero() {
+            (vector / sin, T::cast(2.0) * sin.atan2(self.w))
+        } else {
+            (TVector3::new(T::one(), T::zero(), T::zero()), T::zero())
+        }
+    }
+
+    /// Returns intrinsic Euler angles (in radians) in the given order of rotation described by normalized quaternion,
+    /// so that from_euler(order, first, second, third) gives the same rotation. The second angle is in
+    /// [-pi/2, pi/2] for Tait-Bryan orders and in [0, pi] for proper Euler orders, the others are in [-pi, pi].
+    /// In gimbal lock the third angle is zero
+    /// ```
+    /// use iomath::quaternions::{ Quaternion, EulerOrder };
+    /// 
+    /// let (first, second, third) = Quaternion::from_euler(EulerOrder::XYZ, 0.1, 0.2, 0.3).to_euler(EulerOrder::XYZ);
+    /// assert!((first - 0.1).abs() < 1e-5 && (second - 0.2).abs() < 1e-5 && (third - 0.3).abs() < 1e-5);
+    /// ```
+    pub fn to_euler(self, order: EulerOrder) -> (T, T, T) {
+        // Bernardes and Viollet, "Quaternion to Euler angles conversion: A direct, general and computationally efficient method".
+        // The method is formulated for extrinsic rotations, intrinsic ones are the same with reversed order of axes
+        let [k, j, i] = order.axes();
+        let symmetric = i == k;
+        let k = if symmetric { 3 - i - j } else { k };
+        let sign = if (j + 3 - i) % 3 == 1 { T::one() } else { -T::one() };
+
+        let vector = [self.x, self.y, self.z];
+        let (a, b, c, d) = if symmetric {
+            (self.w, vector[i], vector[j], vector[k] * sign)
+        } else {
+            (self.w - vector[j], vector[i] + vector[k] * sign, vector[j] + self.w, vector[k] * sign - vector[i])
+        };
+
+        let mut second = T::cast(2.0) * c.hypot(d).atan2(a.hypot(b));
+        let half_sum = b.atan2(a);
+        let half_difference = d.atan2(c);
+
+        let epsilon = T::EPSILON.sqrt();
+        let (mut first, mut third) = if second.abs() <= epsilon {
+            (T::zero(), T::cast(2.0) * half_sum)
+        } else if (second - T::PI).abs() <= epsilon {
+            (T::zero(), T::cast(2.0) * half_difference)
+        } else {
+            (half_sum - half_difference, half_sum + half_difference)
+        };
+
+        if !symmetric {
+            third *= sign;
+            second -= T::FRAC_PI_2;
+        }
+
+        std::mem::swap(&mut first, &mut third);
+        let wrap = |angle: T| if angle < -T::PI { angle + T::cast(2.0) * T::PI } else if angle > T::PI { angle - T::cast(2.0) * T::PI } else { angle };
+        (wrap(first), wrap(second), wrap(third))
+    }
+
+    /// Creates normalized quaternion from rotation matrix. The matrix is orthonormalized first (Gram-Schmidt, x column is kept),
+    /// so slight scale or shear drift is tolerated. Uses Shepperd's method, which is stable for rotations near pi
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::matrices::Matrix3;
+    /// 
+    /// let quaternion = Quaternion::from_rotation_matrix(Matrix3::from(Quaternion::from_rotation_z(1.0)));
+    /// assert!((quaternion - Quaternion::from_rotation_z(1.0)).norm() < 1e-6);
+    /// ```
+    pub fn from_rotation_matrix(matrix: TMatrix3<T>) -> Self {
+        let x = matrix.x.normalize();
+        let y = (matrix.y - x * x.dot(matrix.y)).normalize();
+        let z = x.cross(y);
+
+        let trace = x.x + y.y + z.z;
+        if trace >= x.x && trace >= y.y && trace >= z.z {
+            let w = T::cast(0.5) * (T::one() + trace).sqrt();
+            let scale = T::cast(0.25) / w;
+            Self::new(w, (y.z - z.y) * scale, (z.x - x.z) * scale, (x.y - y.x) * scale)
+        } else if x.x >= y.y && x.x >= z.z {
+            let quat_x = T::cast(0.5) * (T::one() + x.x - y.y - z.z).sqrt();
+            let scale = T::cast(0.25) / quat_x;
+            Self::new((y.z - z.y) * scale, quat_x, (y.x + x.y) * scale, (z.x + x.z) * scale)
+        } else if y.y >= z.z {
+            let quat_y = T::cast(0.5) * (T::one() - x.x + y.y - z.z).sqrt();
+            let scale = T::cast(0.25) / quat_y;
+            Self::new((z.x - x.z) * scale, (y.x + x.y) * scale, quat_y, (z.y + y.z) * scale)
+        } else {
+            let quat_z = T::cast(0.5) * (T::one() - x.x - y.y + z.z).sqrt();
+            let scale = T::cast(0.25) / quat_z;
+            Self::new((x.y - y.x) * scale, (z.x + x.z) * scale, (z.y + y.z) * scale, quat_z)
+        }
+    }
+
+    /// Returns exponential of the quaternion. For pure quaternion (0, v) the result is the normalized quaternion
+    /// which rotates by angle 2|v| around v, so exp(0.5 * dt * (0, omega)) integrates angular velocity omega over dt
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let quaternion = Quaternion::new(0.0, 0.0, 0.0, std::f32::consts::FRAC_PI_4).exp();
+    /// assert!((quaternion - Quaternion::from_rotation_z(std::f32::consts::FRAC_PI_2)).norm() < 1e-6);
+    /// ```
+    pub fn exp(self) -> Self {
+        let vector = TVector3::from(self);
+        let angle = vector.length();
+        let exp_w = self.w.exp();
+        if angle > T::zero() {
+            let (sin, cos) = angle.sin_cos();
+            let vector = vector * (exp_w * sin / angle);
+            Self::new(exp_w * cos, vector.x, vector.y, vector.z)
+        } else {
+            Self::new(exp_w, T::zero(), T::zero(), T::zero())
+        }
+    }
+
+    /// Returns natural logarithm of the quaternion, the inverse of exp. For normalized quaternion the result is pure,
+    /// with vector part equal to half of the rotation vector. Negative real quaternions are mapped onto x axis
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let quaternion = Quaternion::from_rotation_x(1.0).ln();
+    /// assert!((quaternion - Quaternion::new(0.0, 0.5, 0.0, 0.0)).norm() < 1e-6);
+    /// ```
+    pub fn ln(self) -> Self {
+        let vector = TVector3::from(self);
+        let vector_length = vector.length();
+        let norm = self.norm();
+        if vector_length > T::zero() {
+            let vector = vector * (vector_length.atan2(self.w) / vector_length);
+            Self::new(norm.ln(), vector.x, vector.y, vector.z)
+        } else if self.w < T::zero() {
+            Self::new(norm.ln(), T::PI, T::zero(), T::zero())
+        } else {
+            Self::new(norm.ln(), T::zero(), T::zero(), T::zero())
+        }
+    }
+
+    /// Raises the quaternion to real power exponent as exp(exponent * ln(self)).
+    /// For normalized quaternion this scales the angle of rotation by exponent
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let quaternion = Quaternion::from_rotation_y(1.0).powf(0.5);
+    /// assert!((quaternion - Quaternion::from_rotation_y(0.5)).norm() < 1e-6);
+    /// ```
+    pub fn powf(self, exponent: T) -> Self {
+        (self.ln() * exponent).exp()
+    }
+
+    /// Creates quaternion from rotation vector, whose direction is axis and length is angle (in radians) of rotation
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let quaternion = Quaternion::from_scaled_axis(Vector3::new(0.0, 0.0, 2.0));
+    /// assert_eq!(quaternion, Quaternion::from_rotation_z(2.0));
+    /// ```
+    pub fn from_scaled_axis(scaled_axis: TVector3<T>) -> Self {
+        let angle = scaled_axis.length();
+        if angle > T::zero() {
+            Self::from_axis_angle(scaled_axis / angle, angle)
+        } else {
+            Self::identity()
+        }
+    }
+
+    /// Returns rotation vector (axis multiplied by angle in radians, from 0 to pi) of rotation described by normalized quaternion
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let scaled_axis = Quaternion::from_rotation_z(2.0).to_scaled_axis();
+    /// assert!(scaled_axis.distance(Vector3::new(0.0, 0.0, 2.0)) < 1e-6);
+    /// ```
+    pub fn to_scaled_axis(self) -> TVector3<T> {
+        let quaternion = if self.w < T::zero() { -self } else { self };
+        let (axis, angle) = quaternion.to_axis_angle();
+        axis * angle
+    }
+
+    /// Returns normalized linear interpolation between two normalized quaternions along the shortest path
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let quaternion = Quaternion::identity().nlerp(Quaternion::from_rotation_z(1.0), 0.5);
+    /// assert!((quaternion - Quaternion::from_rotation_z(0.5)).norm() < 1e-6);
+    /// ```
+    pub fn nlerp(self, other: Self, t: T) -> Self {
+        let other = if self.dot(other) < T::zero() { -other } else { other };
+        self.lerp(other, t).normalize()
+    }
+
+    /// Returns spherical linear interpolation between two normalized quaternions along the shortest path.
+    /// Falls back to nlerp when the quaternions are too close for the division by sine of angle between them to be stable
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let quaternion = Quaternion::identity().slerp(Quaternion::from_rotation_z(1.0), 0.25);
+    /// assert!((quaternion - Quaternion::from_rotation_z(0.25)).norm() < 1e-6);
+    /// ```
+    pub fn slerp(self, other: Self, t: T) -> Self {
+        let mut dot = self.dot(other);
+        let other = if dot < T::zero() {
+            dot = -dot;
+            -other
+        } else {
+            other
+        };
+
+        if dot > T::cast(0.9995) {
+            return self.lerp(other, t).normalize();
+        }
+
+        let angle = dot.acos();
+        let sin = angle.sin();
+        self * (((T::one() - t) * angle).sin() / sin) + other * ((t * angle).sin() / sin)
+    }
+
+    /// Returns spherical linear interpolation without shortest path correction, as used by squad
+    fn slerp_no_invert(self, other: Self, t: T) -> Self {
+        let dot = self.dot(other);
+        if dot.abs() > T::cast(0.9995) {
+            return self.lerp(other, t).normalize();
+        }
+
+        let angle = dot.acos();
+        let sin = angle.sin();
+        self * (((T::one() - t) * angle).sin() / sin) + other * ((t * angle).sin() / sin)
+    }
+
+    /// Returns spherical quadrangle interpolation between normalized quaternions self and other,
+    /// whose control points are first_control and second_control (see `squad_control_point`)
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let (start, end) = (Quaternion::identity(), Quaternion::from_rotation_z(1.0));
+    /// let quaternion = start.squad(end, start, end, 0.5);
+    /// assert!((quaternion - start.slerp(end, 0.5)).norm() < 1e-6);
+    /// ```
+    pub fn squad(self, other: Self, first_control: Self, second_control: Self, t: T) -> Self {
+        self.slerp_no_invert(other, t).slerp_no_invert(first_control.slerp_no_invert(second_control, t), T::cast(2.0) * t * (T::one() - t))
+    }
+
+    /// Returns squad control point of normalized keyframe self, whose neighbour keyframes are previous and next.
+    /// The keyframes should lie in the same hemisphere, i.e. their consecutive dot products should be non-negative
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let keyframe = Quaternion::from_rotation_z(1.0);
+    /// let control = keyframe.squad_control_point(Quaternion::identity(), Quaternion::from_rotation_z(2.0));
+    /// assert!((control - keyframe).norm() < 1e-6);
+    /// ```
+    pub fn squad_control_point(self, previous: Self, next: Self) -> Self {
+        let inverse = self.conjugate();
+        let tangent = ((inverse * next).ln() + (inverse * previous).ln()) * T::cast(-0.25);
+        self * tangent.exp()
+    }
+
+    /// Returns squad spline interpolation over a sequence of normalized keyframes which are spaced evenly in time,
+    /// t from 0 to keyframes.len() - 1 goes through all of them. Keyframes are brought to the same hemisphere as their
+    /// predecessors, so sequences with opposite signs of the same rotation are handled
+    /// ```
+    /// use iomath::quaternions::Quaternion;
+    /// 
+    /// let keyframes = [Quaternion::identity(), Quaternion::from_rotation_z(1.0), Quaternion::from_rotation_y(1.0)];
+    /// assert!((Quaternion::squad_spline(&keyframes, 1.0) - keyframes[1]).norm() < 1e-6);
+    /// assert!((Quaternion::squad_spline(&keyframes, 2.0) - keyframes[2]).norm() < 1e-6);
+    /// ```
+    /// # Panics
+    /// Panics if keyframes is empty
+    pub fn squad_spline(keyframes: &[Self], t: T) -> Self {
+        assert!(!keyframes.is_empty(), "squad_spline requires at least one keyframe");
+
+        let last = keyframes.len() - 1;
+        let t = t.max(T::zero()).min(T::cast(last));
+        let segment = t.floor().as_usize().min(last.saturating_sub(1));
+        let local = t - T::cast(segment);
+
+        let at = |index: isize| keyframes[index.max(0).min(last as isize) as usize];
+        let align = |reference: Self, quaternion: Self| if reference.dot(quaternion) < T::zero() { -quaternion } else { quaternion };
+
+        let index = segment as isize;
+        let current = at(index);
+        let previous = align(current, at(index - 1));
+        let next = align(current, at(index + 1));
+        let after_next = align(next, at(index + 2));
+
+        let first_control = current.squad_control_point(previous, next);
+        let second_control = next.squad_control_point(current, after_next);
+        current.squad(next, first_control, second_control, local)
+    }
+}
+
+impl<T> Copy for TQuaternion<T> where T : Copy { }
+impl<T> Clone for TQuaternion<T> where T : Copy {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> From<[T; 4]> for TQuaternion<T> where T : Copy {
+    fn from(array: [T; 4]) -> Self {
+        Self::new(array[0], array[1], array[2], array[3])
+    }
+}
+
+impl<T> From<TQuaternion<T>> for [T; 4] {
+    fn from(quaternion: TQuaternion<T>) -> Self {
+        [quaternion.w, quaternion.x, quaternion.y, quaternion.z]
+    }
+}
+
+impl<T> From<(T, T, T, T)> for TQuaternion<T> {
+    fn from(tuple: (T, T, T, T)) -> Self {
+        Self::new(tuple.0, tuple.1, tuple.2, tuple.3)
+    }
+}
+
+impl<T> From<TQuaternion<T>> for (T, T, T, T) {
+    fn from(quaternion: TQuaternion<T>) -> Self {
+        (quaternion.w, quaternion.x, quaternion.y, quaternion.z)
+    }
+}
+
 impl<T> PartialEq for TQuaternion<T> where T : PartialEq {
     fn eq(&self, other: &Self) -> bool {
         self.w == other.w &&
@@ -31,6 +679,175 @@ impl<T> PartialEq for TQuaternion<T> where T : PartialEq {
     }
 }
 
+impl<T> Add<TQuaternion<T>> for TQuaternion<T> where T : Add<Output = T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            w: self.w + other.w,
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z
+        }
+    }
+}
+
+impl<T> AddAssign<TQuaternion<T>> for TQuaternion<T> where T : AddAssign {
+    fn add_assign(&mut self, other: Self) {
+        self.w += other.w;
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl<T> Sub<TQuaternion<T>> for TQuaternion<T> where T : Sub<Output = T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            w: self.w - other.w,
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z
+        }
+    }
+}
+
+impl<T> SubAssign<TQuaternion<T>> for TQuaternion<T> where T : SubAssign {
+    fn sub_assign(&mut self, other: Self) {
+        self.w -= other.w;
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+impl<T> Mul<T> for TQuaternion<T> where T : Mul<Output = T> + Copy {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Self {
+            w: self.w * scalar,
+            x: self.x * scalar,
+            y: self.y * scalar,
+            z: self.z * scalar
+        }
+    }
+}
+
+impl<T> Mul<TQuaternion<T>> for TQuaternion<T> where T : Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w
+        }
+    }
+}
+
+impl<T> Mul<TVector3<T>> for TQuaternion<T> where T : Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    type Output = TVector3<T>;
+
+    fn mul(self, vector: TVector3<T>) -> Self::Output {
+        self.rotate_vector(vector)
+    }
+}
+
+impl<T> MulAssign<T> for TQuaternion<T> where T : MulAssign + Copy {
+    fn mul_assign(&mut self, scalar: T) {
+        self.w *= scalar;
+        self.x *= scalar;
+        self.y *= scalar;
+        self.z *= scalar;
+    }
+}
+
+impl<T> MulAssign<TQuaternion<T>> for TQuaternion<T> where T : Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T> Div<T> for TQuaternion<T> where T : Div<Output = T> + Copy {
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self::Output {
+        Self {
+            w: self.w / scalar,
+            x: self.x / scalar,
+            y: self.y / scalar,
+            z: self.z / scalar
+        }
+    }
+}
+
+impl<T> DivAssign<T> for TQuaternion<T> where T : DivAssign + Copy {
+    fn div_assign(&mut self, scalar: T) {
+        self.w /= scalar;
+        self.x /= scalar;
+        self.y /= scalar;
+        self.z /= scalar;
+    }
+}
+
+impl<T> Neg for TQuaternion<T> where T : Neg<Output = T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            w: -self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z
+        }
+    }
+}
+
+impl<T> Default for TQuaternion<T> where T : Zero<T> + One<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T> Eq for TQuaternion<T> where T : Eq { }
+
+impl<T> Hash for TQuaternion<T> where T : Hash {
+    fn hash<H>(&self, state: &mut H) where H : Hasher {
+        self.w.hash(state);
+        self.x.hash(state);
+        self.y.hash(state);
+        self.z.hash(state);
+    }
+}
+
+impl<T> Display for TQuaternion<T> where T : Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "(")?;
+        Display::fmt(&self.w, f)?;
+        write!(f, ", ")?;
+        Display::fmt(&self.x, f)?;
+        write!(f, ", ")?;
+        Display::fmt(&self.y, f)?;
+        write!(f, ", ")?;
+        Display::fmt(&self.z, f)?;
+        write!(f, ")")
+    }
+}
+
+impl<T> FromStr for TQuaternion<T> where T : FromStr {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> std::result::Result<Self, Self::Err> {
+        let mut components = parse_components(string, 4)?.into_iter();
+        let mut next = || components.next().expect("number of components is checked by parse_components");
+        Ok(Self::new(next(), next(), next(), next()))
+    }
+}
+
 impl<T> Debug for TQuaternion<T> where T : Debug {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         f.debug_struct(format!("TQuaternion<{}>", std::any::type_name::<T>()).as_str())
@@ -0,0 +1,96 @@
+use crate::types::basic_types::{ TVector2, TVector3, TVector4, TQuaternion };
+
+macro_rules! swizzle_type {
+    ($t:ident; $x:ident, $y:ident) => { TVector2<$t> };
+    ($t:ident; $x:ident, $y:ident, $z:ident) => { TVector3<$t> };
+    ($t:ident; $x:ident, $y:ident, $z:ident, $w:ident) => { TVector4<$t> };
+}
+
+macro_rules! swizzle_get {
+    ($self:ident; $x:ident, $y:ident) => {
+        TVector2 { x: $self.$x, y: $self.$y }
+    };
+    ($self:ident; $x:ident, $y:ident, $z:ident) => {
+        TVector3 { x: $self.$x, y: $self.$y, z: $self.$z }
+    };
+    ($self:ident; $x:ident, $y:ident, $z:ident, $w:ident) => {
+        TVector4 { x: $self.$x, y: $self.$y, z: $self.$z, w: $self.$w }
+    };
+}
+
+macro_rules! swizzle_set {
+    ($self:ident, $value:ident; $x:ident, $y:ident) => {
+        $self.$x = $value.x;
+        $self.$y = $value.y;
+    };
+    ($self:ident, $value:ident; $x:ident, $y:ident, $z:ident) => {
+        $self.$x = $value.x;
+        $self.$y = $value.y;
+        $self.$z = $value.z;
+    };
+    ($self:ident, $value:ident; $x:ident, $y:ident, $z:ident, $w:ident) => {
+        $self.$x = $value.x;
+        $self.$y = $value.y;
+        $self.$z = $value.z;
+        $self.$w = $value.w;
+    };
+}
+
+macro_rules! impl_swizzles {
+    ([$($type:ident $module:ident::$alias:ident::$base:ident),+] $get:tt $set:tt) => {
+        $(
+            impl_swizzles!(@impl $type $module::$alias::$base $get $set);
+        )+
+    };
+    (@impl $type:ident $module:ident::$alias:ident::$base:ident { $($xyzw:ident $rgba:ident $stpq:ident: $($field:ident),+;)+ } { $($set_xyzw:ident $set_rgba:ident $set_stpq:ident: $($set_field:ident),+;)+ }) => {
+        /// Swizzles in xyzw, rgba and stpq naming, which read components in any order and write distinct components
+        /// ```
+        #[doc = concat!("use iomath::", stringify!($module), "::", stringify!($alias), ";")]
+        /// 
+        #[doc = concat!("let mut value = ", stringify!($alias), " { x: 1.0, y: 2.0, ..", stringify!($alias), "::", stringify!($base), "() };")]
+        /// assert_eq!(value.yx().as_array(), &[2.0, 1.0]);
+        /// assert_eq!(value.gr(), value.yx());
+        /// assert_eq!(value.xxyy().as_array(), &[1.0, 1.0, 2.0, 2.0]);
+        /// 
+        /// value.set_yx((3.0, 4.0).into());
+        /// assert_eq!(value.xy().as_array(), &[4.0, 3.0]);
+        /// ```
+        impl<T> $type<T> where T : Copy {
+            $(
+                #[doc = concat!("Returns vector made of ", stringify!($($field),+), " components")]
+                pub fn $xyzw(self) -> swizzle_type!(T; $($field),+) {
+                    swizzle_get!(self; $($field),+)
+                }
+
+                #[doc = concat!("Same as ", stringify!($xyzw))]
+                pub fn $rgba(self) -> swizzle_type!(T; $($field),+) {
+                    self.$xyzw()
+                }
+
+                #[doc = concat!("Same as ", stringify!($xyzw))]
+                pub fn $stpq(self) -> swizzle_type!(T; $($field),+) {
+                    self.$xyzw()
+                }
+            )+
+
+            $(
+                #[doc = concat!("Sets ", stringify!($($set_field),+), " components to components of value in order")]
+                pub fn $set_xyzw(&mut self, value: swizzle_type!(T; $($set_field),+)) {
+                    swizzle_set!(self, value; $($set_field),+);
+                }
+
+                #[doc = concat!("Same as ", stringify!($set_xyzw))]
+                pub fn $set_rgba(&mut self, value: swizzle_type!(T; $($set_field),+)) {
+                    self.$set_xyzw(value);
+                }
+
+                #[doc = concat!("Same as ", stringify!($set_xyzw))]
+                pub fn $set_stpq(&mut self, value: swizzle_type!(T; $($set_field),+)) {
+                    self.$set_xyzw(value);
+                }
+            )+
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/swizzles.rs"));
\ No newline at end of file
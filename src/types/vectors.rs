@@ -1,8 +1,13 @@
-use crate::types::basic_types::{ TVector2, TVector3, TVector4, TQuaternion };
-use crate::types::basic_types::num_traits::Zero;
+use crate::types::basic_types::{ TVector2, TVector3, TVector4, TQuaternion, CastError, CastErrorKind, ParseError };
+use crate::types::basic_types::num_traits::{ Zero, One, Signed, Float };
 
 use std::ops::*;
 use std::fmt::*;
+use std::convert::TryFrom;
+use std::iter::{ FromIterator, Sum, Product };
+use std::hash::{ Hash, Hasher };
+use std::cmp::Ordering;
+use std::str::FromStr;
 
 impl<T> TVector2<T> where T : Zero<T> + Copy {
     /// Creates TVector2&lt;type&gt; whose x and y equal to zero
@@ -271,7 +276,1449 @@ impl<T> TVector4<T> where T : Zero<T> + Copy {
     }
 }
 
+impl<T> TVector2<T> where T : Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    /// Returns dot product of two TVector2&lt;type&gt;
+    /// ```
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let dot = Vector2::new(1.0, 2.0).dot(Vector2::new(3.0, -4.0));
+    /// assert_eq!(dot, -5.0);
+    /// ```
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns squared length of TVector2&lt;type&gt;
+    /// ```
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let length_squared = Vector2::new(3.0, 4.0).length_squared();
+    /// assert_eq!(length_squared, 25.0);
+    /// ```
+    pub fn length_squared(self) -> T {
+        self.dot(self)
+    }
+
+    /// Returns squared distance between two TVector2&lt;type&gt;
+    /// ```
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let distance_squared = Vector2::new(1.0, 1.0).distance_squared(Vector2::new(4.0, 5.0));
+    /// assert_eq!(distance_squared, 25.0);
+    /// ```
+    pub fn distance_squared(self, other: Self) -> T {
+        (other - self).length_squared()
+    }
+
+    /// Returns perpendicular dot product of two TVector2&lt;type&gt; (z component of their 3D cross product).
+    /// Positive if other is counterclockwise from self, negative if clockwise
+    /// ```
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let perp_dot = Vector2::new(1.0, 0.0).perp_dot(Vector2::new(0.0, 2.0));
+    /// assert_eq!(perp_dot, 2.0);
+    /// ```
+    pub fn perp_dot(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Returns 2D cross product of two TVector2&lt;type&gt;, the same as `perp_dot`
+    /// ```
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let cross = Vector2::new(2.0, 3.0).cross(Vector2::new(4.0, 5.0));
+    /// assert_eq!(cross, -2.0);
+    /// ```
+    pub fn cross(self, other: Self) -> T {
+        self.perp_dot(other)
+    }
+}
+
+impl<T> TVector2<T> where T : Neg<Output = T> + Copy {
+    /// Returns TVector2&lt;type&gt; rotated by 90 degrees counterclockwise
+    /// ```
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let perp = Vector2::new(3.0, 1.0).perp();
+    /// assert_eq!(perp, Vector2::new(-1.0, 3.0));
+    /// ```
+    pub fn perp(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x
+        }
+    }
+}
+
+impl<T> TVector3<T> where T : Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    /// Returns dot product of two TVector3&lt;type&gt;
+    /// ```
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let dot = Vector3::new(1.0, 2.0, 3.0).dot(Vector3::new(4.0, -5.0, 6.0));
+    /// assert_eq!(dot, 12.0);
+    /// ```
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns squared length of TVector3&lt;type&gt;
+    /// ```
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let length_squared = Vector3::new(2.0, 3.0, 6.0).length_squared();
+    /// assert_eq!(length_squared, 49.0);
+    /// ```
+    pub fn length_squared(self) -> T {
+        self.dot(self)
+    }
+
+    /// Returns squared distance between two TVector3&lt;type&gt;
+    /// ```
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let distance_squared = Vector3::new(1.0, 1.0, 1.0).distance_squared(Vector3::new(3.0, 4.0, 7.0));
+    /// assert_eq!(distance_squared, 49.0);
+    /// ```
+    pub fn distance_squared(self, other: Self) -> T {
+        (other - self).length_squared()
+    }
+
+    /// Returns cross product of two TVector3&lt;type&gt;
+    /// ```
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let cross = Vector3::new(1.0, 0.0, 0.0).cross(Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(cross, Vector3::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn cross(self, other: Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x
+        }
+    }
+
+    /// Returns scalar triple product self · (b × c), the signed volume of the parallelepiped spanned by three TVector3&lt;type&gt;
+    /// ```
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let volume = Vector3::new(2.0, 0.0, 0.0).scalar_triple(Vector3::new(0.0, 3.0, 0.0), Vector3::new(0.0, 0.0, 4.0));
+    /// assert_eq!(volume, 24.0);
+    /// ```
+    pub fn scalar_triple(self, b: Self, c: Self) -> T {
+        self.dot(b.cross(c))
+    }
+
+    /// Returns vector triple product self × (b × c)
+    /// ```
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let result = Vector3::new(1.0, 0.0, 0.0).vector_triple(Vector3::new(1.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+    /// assert_eq!(result, Vector3::new(0.0, 0.0, -1.0));
+    /// ```
+    pub fn vector_triple(self, b: Self, c: Self) -> Self {
+        self.cross(b.cross(c))
+    }
+}
+
+impl<T> TVector4<T> where T : Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    /// Returns dot product of two TVector4&lt;type&gt;
+    /// ```
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let dot = Vector4::new(1.0, 2.0, 3.0, 4.0).dot(Vector4::new(5.0, -6.0, 7.0, -8.0));
+    /// assert_eq!(dot, -18.0);
+    /// ```
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Returns squared length of TVector4&lt;type&gt;
+    /// ```
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let length_squared = Vector4::new(1.0, 2.0, 2.0, 4.0).length_squared();
+    /// assert_eq!(length_squared, 25.0);
+    /// ```
+    pub fn length_squared(self) -> T {
+        self.dot(self)
+    }
+
+    /// Returns squared distance between two TVector4&lt;type&gt;
+    /// ```
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let distance_squared = Vector4::new(1.0, 1.0, 1.0, 1.0).distance_squared(Vector4::new(2.0, 3.0, 3.0, 5.0));
+    /// assert_eq!(distance_squared, 25.0);
+    /// ```
+    pub fn distance_squared(self, other: Self) -> T {
+        (other - self).length_squared()
+    }
+}
+
+macro_rules! impl_float_vector {
+    ($vector:ident { $($field:ident),+ }, $alias:ident) => {
+        impl<T> $vector<T> where T : Float {
+            /// Returns length (magnitude) of vector
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { x: 3.0, y: 4.0, ..", stringify!($alias), "::empty() };")]
+            /// assert_eq!(vector.length(), 5.0);
+            /// ```
+            pub fn length(self) -> T {
+                self.length_squared().sqrt()
+            }
+
+            /// Returns distance between two vectors
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let first = ", stringify!($alias), "::from_scalar(1.0);")]
+            #[doc = concat!("let second = ", stringify!($alias), " { x: 4.0, y: 5.0, ..first };")]
+            /// assert_eq!(first.distance(second), 5.0);
+            /// ```
+            pub fn distance(self, other: Self) -> T {
+                (other - self).length()
+            }
+
+            /// Returns vector with the same direction and length of one.
+            /// Components of the result are NaN if the vector's length is zero, use `try_normalize` to handle such case
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { x: 3.0, y: 4.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("assert_eq!(vector.normalize(), ", stringify!($alias), " { x: 0.6, y: 0.8, ..", stringify!($alias), "::empty() });")]
+            /// ```
+            pub fn normalize(self) -> Self {
+                self / self.length()
+            }
+
+            /// Returns vector with the same direction and length of one
+            /// or None if the vector's length is zero or not finite
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { y: 2.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("assert_eq!(vector.try_normalize(), Some(", stringify!($alias), " { y: 1.0, ..", stringify!($alias), "::empty() }));")]
+            #[doc = concat!("assert_eq!(", stringify!($alias), "::empty().try_normalize(), None);")]
+            /// ```
+            pub fn try_normalize(self) -> Option<Self> {
+                let length = self.length();
+                if length > T::zero() && length.is_finite() {
+                    Some(self / length)
+                } else {
+                    None
+                }
+            }
+
+            /// Returns vector of largest integers less than or equal to components
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(-1.5);")]
+            #[doc = concat!("assert_eq!(vector.floor(), ", stringify!($alias), "::from_scalar(-2.0));")]
+            /// ```
+            pub fn floor(self) -> Self {
+                $vector { $($field: self.$field.floor()),+ }
+            }
+
+            /// Returns vector of smallest integers greater than or equal to components
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(-1.5);")]
+            #[doc = concat!("assert_eq!(vector.ceil(), ", stringify!($alias), "::from_scalar(-1.0));")]
+            /// ```
+            pub fn ceil(self) -> Self {
+                $vector { $($field: self.$field.ceil()),+ }
+            }
+
+            /// Returns vector of components rounded to the nearest integer, half-way cases away from zero
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(2.5);")]
+            #[doc = concat!("assert_eq!(vector.round(), ", stringify!($alias), "::from_scalar(3.0));")]
+            /// ```
+            pub fn round(self) -> Self {
+                $vector { $($field: self.$field.round()),+ }
+            }
+
+            /// Returns vector of integer parts of components
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(-1.5);")]
+            #[doc = concat!("assert_eq!(vector.trunc(), ", stringify!($alias), "::from_scalar(-1.0));")]
+            /// ```
+            pub fn trunc(self) -> Self {
+                $vector { $($field: self.$field.trunc()),+ }
+            }
+
+            /// Returns vector of fractional parts of components, i.e. self - self.trunc(), which keeps the sign of each component
+            /// unlike GLSL's fract
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(-1.25);")]
+            #[doc = concat!("assert_eq!(vector.fract(), ", stringify!($alias), "::from_scalar(-0.25));")]
+            /// ```
+            pub fn fract(self) -> Self {
+                $vector { $($field: self.$field.fract()),+ }
+            }
+
+            /// Returns vector with magnitudes of self's components and signs of sign's components
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(2.0);")]
+            #[doc = concat!("assert_eq!(vector.copysign(", stringify!($alias), "::from_scalar(-0.0)), ", stringify!($alias), "::from_scalar(-2.0));")]
+            /// ```
+            pub fn copysign(self, sign: Self) -> Self {
+                $vector { $($field: self.$field.copysign(sign.$field)),+ }
+            }
+
+            /// Returns vector of reciprocals (1 / x) of components
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(4.0);")]
+            #[doc = concat!("assert_eq!(vector.recip(), ", stringify!($alias), "::from_scalar(0.25));")]
+            /// ```
+            pub fn recip(self) -> Self {
+                $vector { $($field: self.$field.recip()),+ }
+            }
+
+            /// Returns vector of square roots of components
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(9.0);")]
+            #[doc = concat!("assert_eq!(vector.sqrt(), ", stringify!($alias), "::from_scalar(3.0));")]
+            /// ```
+            pub fn sqrt(self) -> Self {
+                $vector { $($field: self.$field.sqrt()),+ }
+            }
+
+            /// Returns vector of components raised to the power of exponent
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(2.0);")]
+            #[doc = concat!("assert_eq!(vector.powf(3.0), ", stringify!($alias), "::from_scalar(8.0));")]
+            /// ```
+            pub fn powf(self, exponent: T) -> Self {
+                $vector { $($field: self.$field.powf(exponent)),+ }
+            }
+
+            /// Returns vector of e raised to the power of components
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(0.0);")]
+            #[doc = concat!("assert_eq!(vector.exp(), ", stringify!($alias), "::from_scalar(1.0));")]
+            /// ```
+            pub fn exp(self) -> Self {
+                $vector { $($field: self.$field.exp()),+ }
+            }
+
+            /// Returns vector of natural logarithms of components
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(1.0);")]
+            #[doc = concat!("assert_eq!(vector.ln(), ", stringify!($alias), "::from_scalar(0.0));")]
+            /// ```
+            pub fn ln(self) -> Self {
+                $vector { $($field: self.$field.ln()),+ }
+            }
+
+            /// Returns self * a + b computed component-wise with a single rounding error
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(2.0);")]
+            #[doc = concat!("let result = vector.mul_add(", stringify!($alias), "::from_scalar(3.0), ", stringify!($alias), "::from_scalar(1.0));")]
+            #[doc = concat!("assert_eq!(result, ", stringify!($alias), "::from_scalar(7.0));")]
+            /// ```
+            pub fn mul_add(self, a: Self, b: Self) -> Self {
+                $vector { $($field: self.$field.mul_add(a.$field, b.$field)),+ }
+            }
+
+            /// Returns direction of incident vector reflected from surface with normalized normal (GLSL reflect)
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let incident = ", stringify!($alias), " { x: 1.0, y: -1.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("let normal = ", stringify!($alias), " { y: 1.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("assert_eq!(incident.reflect(normal), ", stringify!($alias), " { x: 1.0, y: 1.0, ..", stringify!($alias), "::empty() });")]
+            /// ```
+            pub fn reflect(self, normal: Self) -> Self {
+                self - normal * (T::cast(2.0) * normal.dot(self))
+            }
+
+            /// Returns direction of normalized incident vector refracted by surface with normalized normal
+            /// and ratio of indices of refraction eta (GLSL refract), or None on total internal reflection
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let incident = ", stringify!($alias), " { x: 0.6, y: -0.8, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("let normal = ", stringify!($alias), " { y: 1.0, ..", stringify!($alias), "::empty() };")]
+            /// assert!(incident.refract(normal, 1.0).unwrap().distance(incident) < 1e-6);
+            /// assert_eq!(incident.refract(normal, 2.0), None);
+            /// ```
+            pub fn refract(self, normal: Self, eta: T) -> Option<Self> {
+                let dot = normal.dot(self);
+                let k = T::one() - eta * eta * (T::one() - dot * dot);
+                if k < T::zero() {
+                    None
+                } else {
+                    Some(self * eta - normal * (eta * dot + k.sqrt()))
+                }
+            }
+
+            /// Returns projection of vector onto other, which must not be zero
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { x: 2.0, y: 3.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("let other = ", stringify!($alias), " { x: 4.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("assert_eq!(vector.project_onto(other), ", stringify!($alias), " { x: 2.0, ..", stringify!($alias), "::empty() });")]
+            /// ```
+            pub fn project_onto(self, other: Self) -> Self {
+                other * (self.dot(other) / other.length_squared())
+            }
+
+            /// Returns component of vector orthogonal to other, which must not be zero
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { x: 2.0, y: 3.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("let other = ", stringify!($alias), " { x: 4.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("assert_eq!(vector.reject_from(other), ", stringify!($alias), " { y: 3.0, ..", stringify!($alias), "::empty() });")]
+            /// ```
+            pub fn reject_from(self, other: Self) -> Self {
+                self - self.project_onto(other)
+            }
+
+            /// Returns self if dot(reference, incident) is negative and -self otherwise (GLSL faceforward)
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let normal = ", stringify!($alias), " { y: 1.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("let incident = ", stringify!($alias), " { y: -1.0, ..", stringify!($alias), "::empty() };")]
+            /// assert_eq!(normal.face_forward(incident, normal), normal);
+            /// assert_eq!(normal.face_forward(-incident, normal), -normal);
+            /// ```
+            pub fn face_forward(self, incident: Self, reference: Self) -> Self {
+                if reference.dot(incident) < T::zero() { self } else { -self }
+            }
+
+            /// Returns angle (in radians, from 0 to pi) between two non-zero vectors.
+            /// Uses Kahan's formula, which is accurate for nearly parallel and nearly opposite vectors
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let first = ", stringify!($alias), " { x: 2.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("let second = ", stringify!($alias), " { y: 3.0, ..", stringify!($alias), "::empty() };")]
+            /// assert!((first.angle_between(second) - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+            /// ```
+            pub fn angle_between(self, other: Self) -> T {
+                let first = self * other.length();
+                let second = other * self.length();
+                T::cast(2.0) * (first - second).length().atan2((first + second).length())
+            }
+        }
+    };
+}
+
+macro_rules! impl_signed_vector {
+    ($vector:ident { $($field:ident),+ }, $alias:ident) => {
+        impl<T> $vector<T> where T : Signed {
+            /// Returns vector of absolute values of components. Overflows for the minimal value of signed integers
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(-2.0);")]
+            #[doc = concat!("assert_eq!(vector.abs(), ", stringify!($alias), "::from_scalar(2.0));")]
+            /// ```
+            pub fn abs(self) -> Self {
+                $vector { $($field: self.$field.abs()),+ }
+            }
+
+            /// Returns vector of signs of components: 1 for positive numbers, -1 for negative numbers and 0 for integer zero.
+            /// Float zeroes give 1 or -1 by their sign, NaN gives NaN
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(-3.0);")]
+            #[doc = concat!("assert_eq!(vector.signum(), ", stringify!($alias), "::from_scalar(-1.0));")]
+            /// ```
+            pub fn signum(self) -> Self {
+                $vector { $($field: self.$field.signum()),+ }
+            }
+        }
+    };
+}
+
+macro_rules! impl_vector_component_wise {
+    ($vector:ident { $first:ident $(, $field:ident)* }, $alias:ident) => {
+        impl<T> $vector<T> where T : PartialOrd + Copy {
+            /// Returns vector of component-wise minimums of two vectors
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let first = ", stringify!($alias), "::from_scalar(1.0);")]
+            #[doc = concat!("let second = ", stringify!($alias), " { x: -1.0, ..", stringify!($alias), "::from_scalar(2.0) };")]
+            #[doc = concat!("assert_eq!(first.min(second), ", stringify!($alias), " { x: -1.0, ..", stringify!($alias), "::from_scalar(1.0) });")]
+            /// ```
+            pub fn min(self, other: Self) -> Self {
+                $vector {
+                    $first: if other.$first < self.$first { other.$first } else { self.$first }
+                    $(, $field: if other.$field < self.$field { other.$field } else { self.$field })*
+                }
+            }
+
+            /// Returns vector of component-wise maximums of two vectors
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let first = ", stringify!($alias), "::from_scalar(1.0);")]
+            #[doc = concat!("let second = ", stringify!($alias), " { x: -1.0, ..", stringify!($alias), "::from_scalar(2.0) };")]
+            #[doc = concat!("assert_eq!(first.max(second), ", stringify!($alias), " { x: 1.0, ..", stringify!($alias), "::from_scalar(2.0) });")]
+            /// ```
+            pub fn max(self, other: Self) -> Self {
+                $vector {
+                    $first: if other.$first > self.$first { other.$first } else { self.$first }
+                    $(, $field: if other.$field > self.$field { other.$field } else { self.$field })*
+                }
+            }
+
+            /// Returns vector whose components are clamped between components of min and max.
+            /// Result is max where min is greater than max
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { x: -5.0, ..", stringify!($alias), "::from_scalar(5.0) };")]
+            #[doc = concat!("let result = vector.clamp(", stringify!($alias), "::from_scalar(0.0), ", stringify!($alias), "::from_scalar(1.0));")]
+            #[doc = concat!("assert_eq!(result, ", stringify!($alias), " { x: 0.0, ..", stringify!($alias), "::from_scalar(1.0) });")]
+            /// ```
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                self.max(min).min(max)
+            }
+
+            /// Returns the smallest component
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { y: -2.0, ..", stringify!($alias), "::from_scalar(1.0) };")]
+            /// assert_eq!(vector.min_element(), -2.0);
+            /// ```
+            pub fn min_element(self) -> T {
+                let mut result = self.$first;
+                $(
+                    if self.$field < result {
+                        result = self.$field;
+                    }
+                )*
+                result
+            }
+
+            /// Returns the largest component
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { y: 3.0, ..", stringify!($alias), "::from_scalar(1.0) };")]
+            /// assert_eq!(vector.max_element(), 3.0);
+            /// ```
+            pub fn max_element(self) -> T {
+                let mut result = self.$first;
+                $(
+                    if self.$field > result {
+                        result = self.$field;
+                    }
+                )*
+                result
+            }
+        }
+
+        impl<T> $vector<T> where T : Add<Output = T> + Mul<Output = T> + Copy {
+            /// Returns sum of components
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { x: 3.0, y: 4.0, ..", stringify!($alias), "::empty() };")]
+            /// assert_eq!(vector.sum(), 7.0);
+            /// ```
+            pub fn sum(self) -> T {
+                self.$first $(+ self.$field)*
+            }
+
+            /// Returns product of components
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { x: 3.0, y: 4.0, ..", stringify!($alias), "::from_scalar(1.0) };")]
+            /// assert_eq!(vector.product(), 12.0);
+            /// ```
+            pub fn product(self) -> T {
+                self.$first $(* self.$field)*
+            }
+        }
+    };
+}
+
+impl_vector_component_wise!(TVector2 { x, y }, Vector2);
+impl_vector_component_wise!(TVector3 { x, y, z }, Vector3);
+impl_vector_component_wise!(TVector4 { x, y, z, w }, Vector4);
+
+impl_float_vector!(TVector2 { x, y }, Vector2);
+impl_float_vector!(TVector3 { x, y, z }, Vector3);
+impl_float_vector!(TVector4 { x, y, z, w }, Vector4);
+
+impl_signed_vector!(TVector2 { x, y }, Vector2);
+impl_signed_vector!(TVector3 { x, y, z }, Vector3);
+impl_signed_vector!(TVector4 { x, y, z, w }, Vector4);
+
+macro_rules! impl_vector_mask {
+    ($vector:ident { $first:ident $(, $field:ident)* }, $alias:ident) => {
+        impl<T> $vector<T> where T : PartialOrd + Copy {
+            /// Returns mask whose components are true where self is less than other
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { x: 1.0, y: 2.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("let mask = vector.cmplt(", stringify!($alias), "::from_scalar(1.0));")]
+            /// assert_eq!((mask.x, mask.y), (false, false));
+            /// ```
+            pub fn cmplt(self, other: Self) -> $vector<bool> {
+                $vector { $first: self.$first < other.$first $(, $field: self.$field < other.$field)* }
+            }
+
+            /// Returns mask whose components are true where self is less than or equal to other
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { x: 1.0, y: 2.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("let mask = vector.cmple(", stringify!($alias), "::from_scalar(1.0));")]
+            /// assert_eq!((mask.x, mask.y), (true, false));
+            /// ```
+            pub fn cmple(self, other: Self) -> $vector<bool> {
+                $vector { $first: self.$first <= other.$first $(, $field: self.$field <= other.$field)* }
+            }
+
+            /// Returns mask whose components are true where self equals to other
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { x: 1.0, y: 2.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("let mask = vector.cmpeq(", stringify!($alias), "::from_scalar(1.0));")]
+            /// assert_eq!((mask.x, mask.y), (true, false));
+            /// ```
+            pub fn cmpeq(self, other: Self) -> $vector<bool> {
+                $vector { $first: self.$first == other.$first $(, $field: self.$field == other.$field)* }
+            }
+
+            /// Returns mask whose components are true where self does not equal to other
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { x: 1.0, y: 2.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("let mask = vector.cmpne(", stringify!($alias), "::from_scalar(1.0));")]
+            /// assert_eq!((mask.x, mask.y), (false, true));
+            /// ```
+            pub fn cmpne(self, other: Self) -> $vector<bool> {
+                $vector { $first: self.$first != other.$first $(, $field: self.$field != other.$field)* }
+            }
+
+            /// Returns mask whose components are true where self is greater than or equal to other
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { x: 1.0, y: 2.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("let mask = vector.cmpge(", stringify!($alias), "::from_scalar(1.0));")]
+            /// assert_eq!((mask.x, mask.y), (true, true));
+            /// ```
+            pub fn cmpge(self, other: Self) -> $vector<bool> {
+                $vector { $first: self.$first >= other.$first $(, $field: self.$field >= other.$field)* }
+            }
+
+            /// Returns mask whose components are true where self is greater than other
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { x: 1.0, y: 2.0, ..", stringify!($alias), "::empty() };")]
+            #[doc = concat!("let mask = vector.cmpgt(", stringify!($alias), "::from_scalar(1.0));")]
+            /// assert_eq!((mask.x, mask.y), (false, true));
+            /// ```
+            pub fn cmpgt(self, other: Self) -> $vector<bool> {
+                $vector { $first: self.$first > other.$first $(, $field: self.$field > other.$field)* }
+            }
+        }
+
+        impl<T> $vector<T> {
+            /// Returns vector whose components are taken from if_true where mask is true and from if_false otherwise
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let mask = ", stringify!($alias), " { x: 1.0, ..", stringify!($alias), "::empty() }.cmpgt(", stringify!($alias), "::empty());")]
+            #[doc = concat!("let result = ", stringify!($alias), "::select(mask, ", stringify!($alias), "::from_scalar(1.0), ", stringify!($alias), "::from_scalar(-1.0));")]
+            #[doc = concat!("assert_eq!(result, ", stringify!($alias), " { x: 1.0, ..", stringify!($alias), "::from_scalar(-1.0) });")]
+            /// ```
+            pub fn select(mask: $vector<bool>, if_true: Self, if_false: Self) -> Self {
+                $vector {
+                    $first: if mask.$first { if_true.$first } else { if_false.$first }
+                    $(, $field: if mask.$field { if_true.$field } else { if_false.$field })*
+                }
+            }
+        }
+
+        impl $vector<bool> {
+            /// Returns true if any component is true
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let mask = ", stringify!($alias), " { x: 1.0, ..", stringify!($alias), "::empty() }.cmpgt(", stringify!($alias), "::empty());")]
+            /// assert!(mask.any());
+            #[doc = concat!("assert!(!", stringify!($alias), "::empty().cmpgt(", stringify!($alias), "::empty()).any());")]
+            /// ```
+            pub fn any(self) -> bool {
+                self.$first $(|| self.$field)*
+            }
+
+            /// Returns true if all components are true
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let mask = ", stringify!($alias), " { x: 1.0, ..", stringify!($alias), "::empty() }.cmpgt(", stringify!($alias), "::empty());")]
+            /// assert!(!mask.all());
+            /// assert!(mask.cmpeq(mask).all());
+            /// ```
+            pub fn all(self) -> bool {
+                self.$first $(&& self.$field)*
+            }
+
+            /// Returns true if no component is true
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let mask = ", stringify!($alias), " { x: 1.0, ..", stringify!($alias), "::empty() }.cmpgt(", stringify!($alias), "::empty());")]
+            /// assert!(!mask.none());
+            #[doc = concat!("assert!(", stringify!($alias), "::empty().cmpgt(", stringify!($alias), "::empty()).none());")]
+            /// ```
+            pub fn none(self) -> bool {
+                !self.any()
+            }
+        }
+
+        impl<T> Not for $vector<T> where T : Not<Output = T> {
+            type Output = Self;
+
+            fn not(self) -> Self::Output {
+                $vector { $first: !self.$first $(, $field: !self.$field)* }
+            }
+        }
+    };
+}
+
+impl_vector_mask!(TVector2 { x, y }, Vector2);
+impl_vector_mask!(TVector3 { x, y, z }, Vector3);
+impl_vector_mask!(TVector4 { x, y, z, w }, Vector4);
+
+impl<T> TVector2<T> {
+    /// Returns reference to the component at index, or None if the index is out of bounds
+    /// ```
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let vector = Vector2::new(1.5, -2.0);
+    /// 
+    /// assert_eq!(vector.get(1), Some(&-2.0));
+    /// assert_eq!(vector.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match index {
+            0 => Some(&self.x),
+            1 => Some(&self.y),
+            _ => None
+        }
+    }
+
+    /// Returns mutable reference to the component at index, or None if the index is out of bounds
+    /// ```
+    /// use iomath::vectors::Vector2;
+    /// 
+    /// let mut vector = Vector2::new(1.5, -2.0);
+    /// if let Some(x) = vector.get_mut(0) {
+    ///     *x = 4.0;
+    /// }
+    /// 
+    /// assert_eq!(vector[0], 4.0);
+    /// assert!(vector.get_mut(2).is_none());
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match index {
+            0 => Some(&mut self.x),
+            1 => Some(&mut self.y),
+            _ => None
+        }
+    }
+}
+
+impl<T> TVector3<T> {
+    /// Returns reference to the component at index, or None if the index is out of bounds
+    /// ```
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let vector = Vector3::new(1.5, -2.0, 3.0);
+    /// 
+    /// assert_eq!(vector.get(1), Some(&-2.0));
+    /// assert_eq!(vector.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match index {
+            0 => Some(&self.x),
+            1 => Some(&self.y),
+            2 => Some(&self.z),
+            _ => None
+        }
+    }
+
+    /// Returns mutable reference to the component at index, or None if the index is out of bounds
+    /// ```
+    /// use iomath::vectors::Vector3;
+    /// 
+    /// let mut vector = Vector3::new(1.5, -2.0, 3.0);
+    /// if let Some(x) = vector.get_mut(0) {
+    ///     *x = 4.0;
+    /// }
+    /// 
+    /// assert_eq!(vector[0], 4.0);
+    /// assert!(vector.get_mut(3).is_none());
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match index {
+            0 => Some(&mut self.x),
+            1 => Some(&mut self.y),
+            2 => Some(&mut self.z),
+            _ => None
+        }
+    }
+}
+
+impl<T> TVector4<T> {
+    /// Returns reference to the component at index, or None if the index is out of bounds
+    /// ```
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let vector = Vector4::new(1.5, -2.0, 3.0, 0.5);
+    /// 
+    /// assert_eq!(vector.get(1), Some(&-2.0));
+    /// assert_eq!(vector.get(4), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match index {
+            0 => Some(&self.x),
+            1 => Some(&self.y),
+            2 => Some(&self.z),
+            3 => Some(&self.w),
+            _ => None
+        }
+    }
+
+    /// Returns mutable reference to the component at index, or None if the index is out of bounds
+    /// ```
+    /// use iomath::vectors::Vector4;
+    /// 
+    /// let mut vector = Vector4::new(1.5, -2.0, 3.0, 0.5);
+    /// if let Some(x) = vector.get_mut(0) {
+    ///     *x = 4.0;
+    /// }
+    /// 
+    /// assert_eq!(vector[0], 4.0);
+    /// assert!(vector.get_mut(4).is_none());
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match index {
+            0 => Some(&mut self.x),
+            1 => Some(&mut self.y),
+            2 => Some(&mut self.z),
+            3 => Some(&mut self.w),
+            _ => None
+        }
+    }
+}
+
+macro_rules! impl_vector_array {
+    ($vector:ident, $size:expr, $tuple:ty, { $($index:tt: $field:ident),+ }, $alias:ident) => {
+        impl<T> $vector<T> {
+            /// Returns reference to components as array
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::from_scalar(2.0);")]
+            #[doc = concat!("assert_eq!(vector.as_array(), &[2.0; ", stringify!($size), "]);")]
+            /// ```
+            pub fn as_array(&self) -> &[T; $size] {
+                // $vector is #[repr(C)] and all of its fields are T, so it has the layout of [T; $size]
+                unsafe { &*(self as *const Self as *const [T; $size]) }
+            }
+
+            /// Returns mutable reference to components as array
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let mut vector = ", stringify!($alias), "::empty();")]
+            /// vector.as_mut_array()[1] = 2.0;
+            #[doc = concat!("assert_eq!(vector, ", stringify!($alias), " { y: 2.0, ..", stringify!($alias), "::empty() });")]
+            /// ```
+            pub fn as_mut_array(&mut self) -> &mut [T; $size] {
+                // $vector is #[repr(C)] and all of its fields are T, so it has the layout of [T; $size]
+                unsafe { &mut *(self as *mut Self as *mut [T; $size]) }
+            }
+        }
+
+        impl<T> $vector<T> where T : Copy {
+            #[doc = concat!("Creates vector from the first ", stringify!($size), " elements of slice. Panics if the slice is shorter")]
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            /// let slice = [1.5; 8];
+            #[doc = concat!("assert_eq!(", stringify!($alias), "::from_slice(&slice[2..]), ", stringify!($alias), "::from_scalar(1.5));")]
+            /// ```
+            pub fn from_slice(slice: &[T]) -> Self {
+                $vector { $($field: slice[$index]),+ }
+            }
+
+            #[doc = concat!("Writes components to the first ", stringify!($size), " elements of slice. Panics if the slice is shorter")]
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            /// let mut slice = [0.0; 8];
+            #[doc = concat!(stringify!($alias), "::from_scalar(1.5).write_to_slice(&mut slice[2..]);")]
+            /// assert_eq!(slice[..3], [0.0, 0.0, 1.5]);
+            #[doc = concat!("assert_eq!(slice[2 + ", stringify!($size), "..], [0.0; 6 - ", stringify!($size), "]);")]
+            /// ```
+            pub fn write_to_slice(self, slice: &mut [T]) {
+                slice[..$size].copy_from_slice(self.as_array());
+            }
+        }
+
+        impl<T> From<[T; $size]> for $vector<T> where T : Copy {
+            fn from(array: [T; $size]) -> Self {
+                $vector { $($field: array[$index]),+ }
+            }
+        }
+
+        impl<T> From<$vector<T>> for [T; $size] {
+            fn from(vector: $vector<T>) -> Self {
+                [$(vector.$field),+]
+            }
+        }
+
+        impl<T> From<$tuple> for $vector<T> {
+            fn from(tuple: $tuple) -> Self {
+                $vector { $($field: tuple.$index),+ }
+            }
+        }
+
+        impl<T> From<$vector<T>> for $tuple {
+            fn from(vector: $vector<T>) -> Self {
+                ($(vector.$field),+)
+            }
+        }
+    };
+}
+
+impl_vector_array!(TVector2, 2, (T, T), { 0: x, 1: y }, Vector2);
+impl_vector_array!(TVector3, 3, (T, T, T), { 0: x, 1: y, 2: z }, Vector3);
+impl_vector_array!(TVector4, 4, (T, T, T, T), { 0: x, 1: y, 2: z, 3: w }, Vector4);
+
+macro_rules! impl_vector_iter {
+    ($vector:ident, $size:expr, { $($field:ident),+ }, $alias:ident) => {
+        impl<T> $vector<T> {
+            /// Returns iterator over references to components
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), " { x: 3.0, y: 4.0, ..", stringify!($alias), "::empty() };")]
+            /// assert_eq!(vector.iter().fold(0.0, |sum, component| sum + component), 7.0);
+            /// ```
+            pub fn iter(&self) -> std::slice::Iter<'_, T> {
+                self.as_array().iter()
+            }
+
+            /// Returns iterator over mutable references to components
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let mut vector = ", stringify!($alias), "::from_scalar(1.5);")]
+            /// vector.iter_mut().for_each(|component| *component *= 2.0);
+            #[doc = concat!("assert_eq!(vector, ", stringify!($alias), "::from_scalar(3.0));")]
+            /// ```
+            pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+                self.as_mut_array().iter_mut()
+            }
+
+            #[doc = concat!("Creates vector from iterator which yields exactly ", stringify!($size), " components, or returns None otherwise")]
+            /// ```
+            #[doc = concat!("use iomath::vectors::", stringify!($alias), ";")]
+            /// 
+            #[doc = concat!("let vector = ", stringify!($alias), "::try_from_iter(std::iter::repeat(1.5).take(", stringify!($size), "));")]
+            #[doc = concat!("assert_eq!(vector, Some(", stringify!($alias), "::from_scalar(1.5)));")]
+            #[doc = concat!("assert_eq!(", stringify!($alias), "::try_from_iter(std::iter::repeat(1.5).take(", stringify!($size), " + 1)), None);")]
+            /// ```
+            pub fn try_from_iter<I>(iterable: I) -> Option<Self> where I : IntoIterator<Item = T> {
+                let mut iterator = iterable.into_iter();
+                let vector = $vector { $($field: iterator.next()?),+ };
+                if iterator.next().is_none() { Some(vector) } else { None }
+            }
+        }
+
+        impl<T> IntoIterator for $vector<T> {
+            type Item = T;
+            type IntoIter = std::array::IntoIter<T, $size>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                IntoIterator::into_iter([$(self.$field),+])
+            }
+        }
+
+        impl<'a, T> IntoIterator for &'a $vector<T> {
+            type Item = &'a T;
+            type IntoIter = std::slice::Iter<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a, T> IntoIterator for &'a mut $vector<T> {
+            type Item = &'a mut T;
+            type IntoIter = std::slice::IterMut<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+
+        impl<T> FromIterator<T> for $vector<T> {
+            /// # Panics
+            #[doc = concat!("Panics if the iterator does not yield exactly ", stringify!($size), " components, use `try_from_iter` to handle such case")]
+            fn from_iter<I>(iterable: I) -> Self where I : IntoIterator<Item = T> {
+                Self::try_from_iter(iterable).unwrap_or_else(|| panic!("{} must be collected from exactly {} components", stringify!($vector), $size))
+            }
+        }
+
+        impl<T> Sum for $vector<T> where T : Zero<T> + Add<Output = T> + Copy {
+            fn sum<I>(iterator: I) -> Self where I : Iterator<Item = Self> {
+                iterator.fold(Self::empty(), |sum, vector| sum + vector)
+            }
+        }
+
+        impl<'a, T> Sum<&'a $vector<T>> for $vector<T> where T : Zero<T> + Add<Output = T> + Copy {
+            fn sum<I>(iterator: I) -> Self where I : Iterator<Item = &'a Self> {
+                iterator.fold(Self::empty(), |sum, vector| sum + *vector)
+            }
+        }
+
+        impl<T> Product for $vector<T> where T : One<T> + Mul<Output = T> + Copy {
+            fn product<I>(iterator: I) -> Self where I : Iterator<Item = Self> {
+                iterator.fold($vector { $($field: T::one()),+ }, |product, vector| product * vector)
+            }
+        }
+
+        impl<'a, T> Product<&'a $vector<T>> for $vector<T> where T : One<T> + Mul<Output = T> + Copy {
+            fn product<I>(iterator: I) -> Self where I : Iterator<Item = &'a Self> {
+                iterator.fold($vector { $($field: T::one()),+ }, |product, vector| product * *vector)
+            }
+        }
+    };
+}
+
+impl_vector_iter!(TVector2, 2, { x, y }, Vector2);
+impl_vector_iter!(TVector3, 3, { x, y, z }, Vector3);
+impl_vector_iter!(TVector4, 4, { x, y, z, w }, Vector4);
+
+macro_rules! impl_vector_range_index {
+    ($vector:ident, $($range:ty),+) => {
+        $(
+            impl<T> Index<$range> for $vector<T> {
+                type Output = [T];
+
+                fn index(&self, index: $range) -> &Self::Output {
+                    &self.as_array()[index]
+                }
+            }
+
+            impl<T> IndexMut<$range> for $vector<T> {
+                fn index_mut(&mut self, index: $range) -> &mut Self::Output {
+                    &mut self.as_mut_array()[index]
+                }
+            }
+        )+
+    };
+}
+
+impl_vector_range_index!(TVector2, Range<usize>, RangeFrom<usize>, RangeTo<usize>, RangeFull, RangeInclusive<usize>, RangeToInclusive<usize>);
+impl_vector_range_index!(TVector3, Range<usize>, RangeFrom<usize>, RangeTo<usize>, RangeFull, RangeInclusive<usize>, RangeToInclusive<usize>);
+impl_vector_range_index!(TVector4, Range<usize>, RangeFrom<usize>, RangeTo<usize>, RangeFull, RangeInclusive<usize>, RangeToInclusive<usize>);
+
+macro_rules! vector_as {
+    (TVector2, $value:ident, $target:ty) => {
+        TVector2 { x: $value.x as $target, y: $value.y as $target }
+    };
+    (TVector3, $value:ident, $target:ty) => {
+        TVector3 { x: $value.x as $target, y: $value.y as $target, z: $value.z as $target }
+    };
+    (TVector4, $value:ident, $target:ty) => {
+        TVector4 { x: $value.x as $target, y: $value.y as $target, z: $value.z as $target, w: $value.w as $target }
+    };
+}
+
+macro_rules! impl_vector_as {
+    ([$($source:ty),+] $targets:tt) => {
+        $(
+            impl_vector_as!(@impl TVector2, $source, $targets);
+            impl_vector_as!(@impl TVector3, $source, $targets);
+            impl_vector_as!(@impl TVector4, $source, $targets);
+        )+
+    };
+    (@impl $vector:ident, $source:ty, { $($name:ident: $target:ty),+ }) => {
+        impl $vector<$source> {
+            $(
+                #[doc = concat!("Converts components to ", stringify!($target), " with `as`, which may truncate, round or saturate")]
+                pub fn $name(self) -> $vector<$target> {
+                    vector_as!($vector, self, $target)
+                }
+            )+
+        }
+    };
+}
+
+impl_vector_as!([f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize] {
+    as_f32: f32,
+    as_f64: f64,
+    as_i8: i8,
+    as_i16: i16,
+    as_i32: i32,
+    as_i64: i64,
+    as_i128: i128,
+    as_isize: isize,
+    as_u8: u8,
+    as_u16: u16,
+    as_u32: u32,
+    as_u64: u64,
+    as_u128: u128,
+    as_usize: usize
+});
+
+macro_rules! impl_vector_from {
+    ($source:ty => $($target:ty),+) => {
+        $(
+            impl From<TVector2<$source>> for TVector2<$target> {
+                fn from(vector: TVector2<$source>) -> Self {
+                    Self { x: vector.x.into(), y: vector.y.into() }
+                }
+            }
+
+            impl From<TVector3<$source>> for TVector3<$target> {
+                fn from(vector: TVector3<$source>) -> Self {
+                    Self { x: vector.x.into(), y: vector.y.into(), z: vector.z.into() }
+                }
+            }
+
+            impl From<TVector4<$source>> for TVector4<$target> {
+                fn from(vector: TVector4<$source>) -> Self {
+                    Self { x: vector.x.into(), y: vector.y.into(), z: vector.z.into(), w: vector.w.into() }
+                }
+            }
+        )+
+    };
+}
+
+impl_vector_from!(f32 => f64);
+impl_vector_from!(i8 => i16, i32, i64, i128, isize, f32, f64);
+impl_vector_from!(i16 => i32, i64, i128, isize, f32, f64);
+impl_vector_from!(i32 => i64, i128, f64);
+impl_vector_from!(i64 => i128);
+impl_vector_from!(u8 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize, f32, f64);
+impl_vector_from!(u16 => u32, u64, u128, usize, i32, i64, i128, f32, f64);
+impl_vector_from!(u32 => u64, u128, i64, i128, f64);
+impl_vector_from!(u64 => u128, i128);
+
+trait CheckedCast<T> {
+    fn checked_cast(self) -> std::result::Result<T, CastErrorKind>;
+}
+
+fn cast_component<S, T>(value: S, component: usize) -> std::result::Result<T, CastError> where S : CheckedCast<T> {
+    value.checked_cast().map_err(|kind| CastError { component, kind })
+}
+
+macro_rules! impl_checked_cast {
+    (integer $source:ty => $($target:ty),+) => {
+        $(
+            impl CheckedCast<$target> for $source {
+                fn checked_cast(self) -> std::result::Result<$target, CastErrorKind> {
+                    <$target>::try_from(self).map_err(|_| CastErrorKind::Overflow)
+                }
+            }
+        )+
+    };
+    (integer_to_float $source:ty => $($target:ty),+) => {
+        $(
+            impl CheckedCast<$target> for $source {
+                fn checked_cast(self) -> std::result::Result<$target, CastErrorKind> {
+                    let result = self as $target;
+                    if !result.is_finite() {
+                        return Err(CastErrorKind::Overflow);
+                    }
+
+                    // Values rounded up to 2^BITS (or 2^(BITS - 1) for signed types) saturate back to MAX, so they are rejected before the round trip
+                    let min = <$source>::MIN as $target;
+                    let max_exclusive = if <$source>::MIN == 0 { (2.0 as $target).powi(<$source>::BITS as i32) } else { -min };
+                    if result < max_exclusive && result as $source == self {
+                        Ok(result)
+                    } else {
+                        Err(CastErrorKind::PrecisionLoss)
+                    }
+                }
+            }
+        )+
+    };
+    (float_to_integer $source:ty => $($target:ty),+) => {
+        $(
+            impl CheckedCast<$target> for $source {
+                fn checked_cast(self) -> std::result::Result<$target, CastErrorKind> {
+                    if !self.is_finite() {
+                        return Err(CastErrorKind::NonFinite);
+                    }
+
+                    let truncated = self.trunc();
+                    let min = <$target>::MIN as $source;
+                    let max_exclusive = if <$target>::MIN == 0 { (2.0 as $source).powi(<$target>::BITS as i32) } else { -min };
+                    if truncated < min || truncated >= max_exclusive {
+                        Err(CastErrorKind::Overflow)
+                    } else if truncated != self {
+                        Err(CastErrorKind::PrecisionLoss)
+                    } else {
+                        Ok(truncated as $target)
+                    }
+                }
+            }
+        )+
+    };
+    (float $source:ty => $($target:ty),+) => {
+        $(
+            impl CheckedCast<$target> for $source {
+                fn checked_cast(self) -> std::result::Result<$target, CastErrorKind> {
+                    let result = self as $target;
+                    if self.is_finite() && !result.is_finite() {
+                        Err(CastErrorKind::Overflow)
+                    } else if self.is_finite() && result as $source != self {
+                        Err(CastErrorKind::PrecisionLoss)
+                    } else {
+                        Ok(result)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_checked_cast!(float_to_integer f32 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_checked_cast!(float f64 => f32);
+impl_checked_cast!(float_to_integer f64 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_checked_cast!(integer i8 => u8, u16, u32, u64, u128, usize);
+impl_checked_cast!(integer i16 => i8, u8, u16, u32, u64, u128, usize);
+impl_checked_cast!(integer_to_float i32 => f32);
+impl_checked_cast!(integer i32 => i8, i16, isize, u8, u16, u32, u64, u128, usize);
+impl_checked_cast!(integer_to_float i64 => f32, f64);
+impl_checked_cast!(integer i64 => i8, i16, i32, isize, u8, u16, u32, u64, u128, usize);
+impl_checked_cast!(integer_to_float i128 => f32, f64);
+impl_checked_cast!(integer i128 => i8, i16, i32, i64, isize, u8, u16, u32, u64, u128, usize);
+impl_checked_cast!(integer_to_float isize => f32, f64);
+impl_checked_cast!(integer isize => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, usize);
+impl_checked_cast!(integer u8 => i8);
+impl_checked_cast!(integer u16 => i8, i16, isize, u8);
+impl_checked_cast!(integer_to_float u32 => f32);
+impl_checked_cast!(integer u32 => i8, i16, i32, isize, u8, u16, usize);
+impl_checked_cast!(integer_to_float u64 => f32, f64);
+impl_checked_cast!(integer u64 => i8, i16, i32, i64, isize, u8, u16, u32, usize);
+impl_checked_cast!(integer_to_float u128 => f32, f64);
+impl_checked_cast!(integer u128 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+impl_checked_cast!(integer_to_float usize => f32, f64);
+impl_checked_cast!(integer usize => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128);
+
+macro_rules! impl_vector_try_from {
+    ($source:ty => $($target:ty),+) => {
+        $(
+            impl TryFrom<TVector2<$source>> for TVector2<$target> {
+                type Error = CastError;
+
+                fn try_from(vector: TVector2<$source>) -> std::result::Result<Self, Self::Error> {
+                    Ok(Self { x: cast_component(vector.x, 0)?, y: cast_component(vector.y, 1)? })
+                }
+            }
+
+            impl TryFrom<TVector3<$source>> for TVector3<$target> {
+                type Error = CastError;
+
+                fn try_from(vector: TVector3<$source>) -> std::result::Result<Self, Self::Error> {
+                    Ok(Self { x: cast_component(vector.x, 0)?, y: cast_component(vector.y, 1)?, z: cast_component(vector.z, 2)? })
+                }
+            }
+
+            impl TryFrom<TVector4<$source>> for TVector4<$target> {
+                type Error = CastError;
+
+                fn try_from(vector: TVector4<$source>) -> std::result::Result<Self, Self::Error> {
+                    Ok(Self {
+                        x: cast_component(vector.x, 0)?,
+                        y: cast_component(vector.y, 1)?,
+                        z: cast_component(vector.z, 2)?,
+                        w: cast_component(vector.w, 3)?
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_vector_try_from!(f32 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_vector_try_from!(f64 => f32, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_vector_try_from!(i8 => u8, u16, u32, u64, u128, usize);
+impl_vector_try_from!(i16 => i8, u8, u16, u32, u64, u128, usize);
+impl_vector_try_from!(i32 => f32, i8, i16, isize, u8, u16, u32, u64, u128, usize);
+impl_vector_try_from!(i64 => f32, f64, i8, i16, i32, isize, u8, u16, u32, u64, u128, usize);
+impl_vector_try_from!(i128 => f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, u128, usize);
+impl_vector_try_from!(isize => f32, f64, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, usize);
+impl_vector_try_from!(u8 => i8);
+impl_vector_try_from!(u16 => i8, i16, isize, u8);
+impl_vector_try_from!(u32 => f32, i8, i16, i32, isize, u8, u16, usize);
+impl_vector_try_from!(u64 => f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, usize);
+impl_vector_try_from!(u128 => f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+impl_vector_try_from!(usize => f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128);
+
+pub(crate) fn parse_components<T>(string: &str, expected: usize) -> std::result::Result<Vec<T>, ParseError> where T : FromStr {
+    let string = string.trim();
+    let string = match (string.starts_with('('), string.ends_with(')')) {
+        (true, true) => &string[1..string.len() - 1],
+        (false, false) => string,
+        _ => return Err(ParseError::UnbalancedParentheses)
+    };
+
+    let components: Vec<&str> = if string.contains(',') {
+        string.split(',').map(str::trim).collect()
+    } else {
+        string.split_whitespace().collect()
+    };
+
+    if components.len() != expected {
+        return Err(ParseError::WrongComponentCount { expected, found: components.len() });
+    }
+
+    components.iter().enumerate().map(|(index, component)| component.parse().map_err(|_| ParseError::InvalidComponent(index))).collect()
+}
+
+macro_rules! impl_vector_std_traits {
+    ($vector:ident, $size:expr, { $first:ident $(, $field:ident)* }) => {
+        impl<T> Default for $vector<T> where T : Zero<T> + Copy {
+            fn default() -> Self {
+                Self::empty()
+            }
+        }
+
+        impl<T> Eq for $vector<T> where T : Eq { }
+
+        impl<T> Hash for $vector<T> where T : Hash {
+            fn hash<H>(&self, state: &mut H) where H : Hasher {
+                self.$first.hash(state);
+                $(self.$field.hash(state);)*
+            }
+        }
+
+        impl<T> Display for $vector<T> where T : Display {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                write!(f, "(")?;
+                Display::fmt(&self.$first, f)?;
+                $(
+                    write!(f, ", ")?;
+                    Display::fmt(&self.$field, f)?;
+                )*
+                write!(f, ")")
+            }
+        }
+
+        impl<T> FromStr for $vector<T> where T : FromStr {
+            type Err = ParseError;
+
+            fn from_str(string: &str) -> std::result::Result<Self, Self::Err> {
+                Ok(parse_components(string, $size)?.into_iter().collect())
+            }
+        }
+    };
+}
+
+impl_vector_std_traits!(TVector2, 2, { x, y });
+impl_vector_std_traits!(TVector3, 3, { x, y, z });
+impl_vector_std_traits!(TVector4, 4, { x, y, z, w });
+
+macro_rules! impl_vector_ord {
+    ($vector:ident $fields:tt => $($type:ty),+) => {
+        $(
+            impl_vector_ord!(@impl $vector<$type> $fields);
+        )+
+    };
+    (@impl $vector:ident<$type:ty> { $first:ident $(, $field:ident)* }) => {
+        impl PartialOrd for $vector<$type> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        /// Lexicographic order: components are compared in order and the first unequal pair decides.
+        /// Inherent `min`, `max` and `clamp` are component-wise and take precedence in method calls,
+        /// use `Ord::min(a, b)`, `Ord::max(a, b)` and `Ord::clamp(a, min, max)` to pick a whole vector by this order
+        impl Ord for $vector<$type> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                (&self.$first $(, &self.$field)*).cmp(&(&other.$first $(, &other.$field)*))
+            }
+        }
+    };
+}
+
+impl_vector_ord!(TVector2 { x, y } => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, bool);
+impl_vector_ord!(TVector3 { x, y, z } => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, bool);
+impl_vector_ord!(TVector4 { x, y, z, w } => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, bool);
+
+impl Display for CastError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.kind {
+            CastErrorKind::Overflow => write!(f, "component {} is out of range of the target type", self.component),
+            CastErrorKind::NonFinite => write!(f, "component {} is not finite", self.component),
+            CastErrorKind::PrecisionLoss => write!(f, "component {} cannot be represented exactly by the target type", self.component)
+        }
+    }
+}
+
+impl std::error::Error for CastError { }
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ParseError::UnbalancedParentheses => write!(f, "unbalanced parentheses"),
+            ParseError::WrongComponentCount { expected, found } => write!(f, "expected {} components but found {}", expected, found),
+            ParseError::InvalidComponent(index) => write!(f, "component {} is not a valid number", index)
+        }
+    }
+}
+
+impl std::error::Error for ParseError { }
+
 impl<T> Copy for TVector2<T> where T : Copy { }
+#[allow(clippy::non_canonical_clone_impl)]
 impl<T> Clone for TVector2<T> where T : Copy {
     fn clone(&self) -> Self {
         *self
@@ -317,7 +1764,7 @@ impl<T> Index<usize> for TVector2<T> {
         match index {
             0 => &self.x,
             1 => &self.y,
-            _ => &self.y
+            _ => panic!("index out of bounds: TVector2 has 2 components but the index is {}", index)
         }
     }
 }
@@ -327,7 +1774,7 @@ impl<T> IndexMut<usize> for TVector2<T> {
         match index {
             0 => &mut self.x,
             1 => &mut self.y,
-            _ => &mut self.y
+            _ => panic!("index out of bounds: TVector2 has 2 components but the index is {}", index)
         }
     }
 }
@@ -720,6 +2167,7 @@ impl<T> Debug for TVector2<T> where T : Debug {
 }
 
 impl<T> Copy for TVector3<T> where T : Copy { }
+#[allow(clippy::non_canonical_clone_impl)]
 impl<T> Clone for TVector3<T> where T : Copy {
     fn clone(&self) -> Self {
         *self
@@ -770,7 +2218,7 @@ impl<T> Index<usize> for TVector3<T> {
             0 => &self.x,
             1 => &self.y,
             2 => &self.z,
-            _ => &self.z
+            _ => panic!("index out of bounds: TVector3 has 3 components but the index is {}", index)
         }
     }
 }
@@ -781,7 +2229,7 @@ impl<T> IndexMut<usize> for TVector3<T> {
             0 => &mut self.x,
             1 => &mut self.y,
             2 => &mut self.z,
-            _ => &mut self.z
+            _ => panic!("index out of bounds: TVector3 has 3 components but the index is {}", index)
         }
     }
 }
@@ -1217,6 +2665,7 @@ impl<T> Debug for TVector3<T> where T : Debug {
 }
 
 impl<T> Copy for TVector4<T> where T : Copy { }
+#[allow(clippy::non_canonical_clone_impl)]
 impl<T> Clone for TVector4<T> where T : Copy {
     fn clone(&self) -> Self {
         *self
@@ -1272,7 +2721,7 @@ impl<T> Index<usize> for TVector4<T> {
             1 => &self.y,
             2 => &self.z,
             3 => &self.w,
-            _ => &self.w
+            _ => panic!("index out of bounds: TVector4 has 4 components but the index is {}", index)
         }
     }
 }
@@ -1284,7 +2733,7 @@ impl<T> IndexMut<usize> for TVector4<T> {
             1 => &mut self.y,
             2 => &mut self.z,
             3 => &mut self.w,
-            _ => &mut self.w
+            _ => panic!("index out of bounds: TVector4 has 4 components but the index is {}", index)
         }
     }
 }
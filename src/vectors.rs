@@ -1,5 +1,7 @@
 use crate::extended::vectors::*;
 
+pub use crate::types::basic_types::{ CastError, CastErrorKind, ParseError };
+
 pub type Vector2 = F32Vector2;
 pub type FVector2 = F32Vector2;
 pub type IVector2 = I32Vector2;
@@ -0,0 +1,234 @@
+use std::convert::TryFrom;
+
+use iomath::affine::{ Affine2, Affine3, NotAffineError };
+use iomath::matrices::{ Matrix2, Matrix3, Matrix4 };
+use iomath::vectors::{ Vector2, Vector3, Vector4 };
+use iomath::quaternions::Quaternion;
+use iomath::types::basic_types::{ TAffine2, TMatrix3, TVector3 };
+
+fn sample_3() -> Affine3 {
+    Affine3::from_rotation_translation_scale(
+        Quaternion::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), 0.9),
+        Vector3::new(1.0, -2.0, 3.0),
+        Vector3::new(2.0, 0.5, 1.5)
+    )
+}
+
+fn sample_2() -> Affine2 {
+    Affine2::from_angle_translation_scale(0.7, Vector2::new(-1.0, 2.0), Vector2::new(3.0, 0.5))
+}
+
+#[test]
+fn affine_2_identity() {
+    let affine = Affine2::identity();
+
+    assert_eq!(affine.matrix, Matrix2::identity());
+    assert_eq!(affine.transform_point2(Vector2::new(1.0, 2.0)), Vector2::new(1.0, 2.0));
+}
+
+#[test]
+fn affine_2_from_translation() {
+    let affine = Affine2::from_translation(Vector2::new(1.0, 2.0));
+
+    assert_eq!(affine, Affine2::new(Matrix2::identity(), Vector2::new(1.0, 2.0)));
+}
+
+#[test]
+fn affine_2_from_scale() {
+    let affine = Affine2::from_scale(Vector2::new(2.0, 3.0));
+
+    assert_eq!(affine, Affine2::new(Matrix2::from_diagonal(Vector2::new(2.0, 3.0)), Vector2::new(0.0, 0.0)));
+}
+
+#[test]
+fn affine_2_from_angle() {
+    let affine = Affine2::from_angle(std::f32::consts::FRAC_PI_2);
+
+    assert!(affine.transform_point2(Vector2::new(0.0, 1.0)).distance(Vector2::new(-1.0, 0.0)) < 1e-6);
+}
+
+#[test]
+fn affine_2_from_angle_translation_scale() {
+    let affine = sample_2();
+    let expected = Affine2::from_translation(Vector2::new(-1.0, 2.0)) * Affine2::from_angle(0.7) * Affine2::from_scale(Vector2::new(3.0, 0.5));
+    let point = Vector2::new(0.25, -4.0);
+
+    assert!(affine.transform_point2(point).distance(expected.transform_point2(point)) < 1e-5);
+}
+
+#[test]
+fn affine_2_transform_point2() {
+    let affine = Affine2::new(Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)), Vector2::new(5.0, 6.0));
+
+    assert_eq!(affine.transform_point2(Vector2::new(1.0, 1.0)), Vector2::new(9.0, 12.0));
+}
+
+#[test]
+fn affine_2_transform_vector2() {
+    let affine = Affine2::new(Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)), Vector2::new(5.0, 6.0));
+
+    assert_eq!(affine.transform_vector2(Vector2::new(1.0, 1.0)), Vector2::new(4.0, 6.0));
+}
+
+#[test]
+fn affine_2_inverse() {
+    let affine = sample_2();
+    let point = Vector2::new(3.0, -1.5);
+
+    assert!(affine.inverse().unwrap().transform_point2(affine.transform_point2(point)).distance(point) < 1e-5);
+    assert_eq!(Affine2::from_scale(Vector2::new(1.0, 0.0)).inverse(), None);
+}
+
+#[test]
+fn affine_2_mul() {
+    let left = sample_2();
+    let right = Affine2::from_translation(Vector2::new(2.0, 1.0)) * Affine2::from_angle(-1.2);
+    let point = Vector2::new(0.5, 1.5);
+    let mut assigned = left;
+    assigned *= right;
+
+    assert!((left * right).transform_point2(point).distance(left.transform_point2(right.transform_point2(point))) < 1e-5);
+    assert_eq!(assigned, left * right);
+}
+
+#[test]
+fn affine_2_to_matrix_3() {
+    let affine = Affine2::new(Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)), Vector2::new(5.0, 6.0));
+    let matrix = Matrix3::from(affine);
+
+    assert_eq!(matrix, Matrix3::new(Vector3::new(1.0, 2.0, 0.0), Vector3::new(3.0, 4.0, 0.0), Vector3::new(5.0, 6.0, 1.0)));
+    assert_eq!(Affine2::try_from(matrix), Ok(affine));
+}
+
+#[test]
+fn affine_2_try_from_projective_matrix_3() {
+    let matrix = Matrix3::new(Vector3::new(1.0, 0.0, 0.5), Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+
+    assert_eq!(Affine2::try_from(matrix), Err(NotAffineError));
+}
+
+#[test]
+fn affine_2_try_from_inverse_matrix_3() {
+    let affine = sample_2();
+    let inverse = Affine2::try_from(Matrix3::from(affine).inverse().unwrap()).unwrap();
+    let point = Vector2::new(0.5, -1.5);
+
+    assert!(inverse.transform_point2(affine.transform_point2(point)).distance(point) < 1e-5);
+    assert_eq!(TAffine2::try_from(TMatrix3::new(TVector3::new(1, 0, 0), TVector3::new(0, 1, 0), TVector3::new(0, 0, 1))), Ok(TAffine2::identity()));
+    assert_eq!(TAffine2::try_from(TMatrix3::new(TVector3::new(1, 0, 0), TVector3::new(0, 1, 0), TVector3::new(0, 0, 2))), Err(NotAffineError));
+}
+
+#[test]
+fn affine_3_identity() {
+    let affine = Affine3::identity();
+
+    assert_eq!(affine.matrix, Matrix3::identity());
+    assert_eq!(affine.transform_point3(Vector3::new(1.0, 2.0, 3.0)), Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn affine_3_from_translation() {
+    let affine = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0));
+
+    assert_eq!(affine, Affine3::new(Matrix3::identity(), Vector3::new(1.0, 2.0, 3.0)));
+    assert_eq!(Matrix4::from(affine), Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)));
+}
+
+#[test]
+fn affine_3_from_scale() {
+    let affine = Affine3::from_scale(Vector3::new(2.0, 3.0, 4.0));
+
+    assert_eq!(Matrix4::from(affine), Matrix4::from_scale(Vector3::new(2.0, 3.0, 4.0)));
+}
+
+#[test]
+fn affine_3_from_quaternion() {
+    let rotation = Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 0.5);
+
+    assert_eq!(Matrix4::from(Affine3::from_quaternion(rotation)), Matrix4::from_quaternion(rotation));
+}
+
+#[test]
+fn affine_3_from_rotation_translation_scale() {
+    let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), 0.9);
+    let matrix = Matrix4::from_rotation_translation_scale(rotation, Vector3::new(1.0, -2.0, 3.0), Vector3::new(2.0, 0.5, 1.5));
+
+    assert_eq!(Matrix4::from(sample_3()), matrix);
+}
+
+#[test]
+fn affine_3_transform_point3() {
+    let affine = sample_3();
+    let point = Vector3::new(0.5, -1.0, 2.0);
+
+    assert!(affine.transform_point3(point).distance(Matrix4::from(affine).transform_point3(point)) < 1e-6);
+}
+
+#[test]
+fn affine_3_transform_vector3() {
+    let affine = sample_3();
+    let vector = Vector3::new(0.5, -1.0, 2.0);
+
+    assert!(affine.transform_vector3(vector).distance(Matrix4::from(affine).transform_vector3(vector)) < 1e-6);
+}
+
+#[test]
+fn affine_3_inverse() {
+    let affine = sample_3();
+    let inverse = Matrix4::from(affine.inverse().unwrap());
+    let expected = Matrix4::from(affine).inverse().unwrap();
+
+    for column in 0..4 {
+        assert!((inverse[column] - expected[column]).length() < 1e-5);
+    }
+    assert_eq!(Affine3::from_scale(Vector3::new(1.0, 1.0, 0.0)).inverse(), None);
+}
+
+#[test]
+fn affine_3_mul() {
+    let left = sample_3();
+    let right = Affine3::from_translation(Vector3::new(2.0, 1.0, 0.0)) * Affine3::from_scale(Vector3::new(1.0, 2.0, 3.0));
+    let product = Matrix4::from(left * right);
+    let expected = Matrix4::from(left) * Matrix4::from(right);
+    let mut assigned = left;
+    assigned *= right;
+
+    for column in 0..4 {
+        assert!((product[column] - expected[column]).length() < 1e-5);
+    }
+    assert_eq!(assigned, left * right);
+}
+
+#[test]
+fn affine_3_from_matrix_4() {
+    let matrix = Matrix4::new(
+        Vector4::new(1.0, 2.0, 3.0, 0.0),
+        Vector4::new(4.0, 5.0, 6.0, 0.0),
+        Vector4::new(7.0, 8.0, 9.0, 0.0),
+        Vector4::new(10.0, 11.0, 12.0, 1.0)
+    );
+    let affine = Affine3::try_from(matrix).unwrap();
+
+    assert_eq!(affine.matrix, Matrix3::from(matrix));
+    assert_eq!(affine.translation, Vector3::new(10.0, 11.0, 12.0));
+    assert_eq!(Matrix4::from(affine), matrix);
+}
+
+#[test]
+fn affine_3_try_from_perspective_matrix_4() {
+    let perspective = Matrix4::perspective_rh_gl(1.0, 1.5, 0.1, 100.0);
+
+    assert_eq!(Affine3::try_from(perspective), Err(NotAffineError));
+}
+
+#[test]
+fn affine_3_try_from_inverse_matrix_4() {
+    let affine = sample_3();
+    let inverse = Affine3::try_from(Matrix4::from(affine).inverse().unwrap()).unwrap();
+    let point = Vector3::new(0.5, -1.5, 2.0);
+    let mut perturbed = Matrix4::from(affine);
+    perturbed.x.w = 1e-7;
+
+    assert!(inverse.transform_point3(affine.transform_point3(point)).distance(point) < 1e-5);
+    assert!(Affine3::try_from(perturbed).is_ok());
+}
\ No newline at end of file
@@ -0,0 +1,75 @@
+use iomath::functions::*;
+use iomath::vectors::{ Vector2, Vector3, Vector4 };
+use iomath::extended::vectors::F64Vector3;
+
+#[test]
+fn functions_mix() {
+    assert_eq!(mix(2.0, 6.0, 0.25), 3.0);
+    assert_eq!(mix(2.0_f64, 6.0, 1.0), 6.0);
+    assert_eq!(mix(Vector2::new(0.0, 10.0), Vector2::new(4.0, 20.0), Vector2::new(0.5, 0.1)), Vector2::new(2.0, 11.0));
+}
+
+#[test]
+fn functions_lerp() {
+    let x = Vector3::new(1.0, -1.0, 0.0);
+    let y = Vector3::new(3.0, 1.0, 8.0);
+    let a = Vector3::from_scalar(0.75);
+
+    assert_eq!(lerp(x, y, a), mix(x, y, a));
+    assert_eq!(lerp(x, y, a), Vector3::new(2.5, 0.5, 6.0));
+}
+
+#[test]
+fn functions_step() {
+    assert_eq!(step(0.5, 0.25), 0.0);
+    assert_eq!(step(0.5, 0.5), 1.0);
+    assert_eq!(step(Vector4::from_scalar(1.0), Vector4::new(0.0, 1.0, 2.0, -1.0)), Vector4::new(0.0, 1.0, 1.0, 0.0));
+}
+
+#[test]
+fn functions_smoothstep() {
+    assert_eq!(smoothstep(0.0, 1.0, -1.0), 0.0);
+    assert_eq!(smoothstep(0.0, 1.0, 0.25), 0.15625);
+    assert_eq!(smoothstep(0.0, 1.0, 0.5), 0.5);
+    assert_eq!(smoothstep(0.0, 1.0, 2.0), 1.0);
+    assert_eq!(smoothstep(Vector2::new(0.0, 2.0), Vector2::new(2.0, 4.0), Vector2::new(1.0, 5.0)), Vector2::new(0.5, 1.0));
+}
+
+#[test]
+fn functions_smootherstep() {
+    assert_eq!(smootherstep(0.0, 1.0, -1.0), 0.0);
+    assert_eq!(smootherstep(0.0, 1.0, 0.25), 0.103515625);
+    assert_eq!(smootherstep(0.0, 1.0, 0.5), 0.5);
+    assert_eq!(smootherstep(0.0, 1.0, 3.0), 1.0);
+    assert_eq!(smootherstep(Vector2::from_scalar(0.0), Vector2::from_scalar(4.0), Vector2::new(2.0, 1.0)), Vector2::new(0.5, 0.103515625));
+}
+
+#[test]
+fn functions_inverse_lerp() {
+    assert_eq!(inverse_lerp(2.0, 6.0, 3.0), 0.25);
+    assert_eq!(inverse_lerp(2.0, 6.0, 10.0), 2.0);
+    assert_eq!(inverse_lerp(Vector2::new(0.0, 10.0), Vector2::new(4.0, 20.0), Vector2::new(2.0, 11.0)), Vector2::new(0.5, 0.1));
+}
+
+#[test]
+fn functions_remap() {
+    assert_eq!(remap(5.0, (0.0, 10.0), (100.0, 200.0)), 150.0);
+    assert_eq!(remap(15.0, (0.0, 10.0), (0.0, -1.0)), -1.5);
+    assert_eq!(remap(F64Vector3::new(0.0, 0.5, 1.0), (F64Vector3::from_scalar(0.0), F64Vector3::from_scalar(1.0)), (F64Vector3::from_scalar(-1.0), F64Vector3::from_scalar(1.0))), F64Vector3::new(-1.0, 0.0, 1.0));
+}
+
+#[test]
+fn functions_saturate() {
+    assert_eq!(saturate(-0.5), 0.0);
+    assert_eq!(saturate(0.5), 0.5);
+    assert_eq!(saturate(1.5), 1.0);
+    assert_eq!(saturate(Vector3::new(-2.0, 0.25, 2.0)), Vector3::new(0.0, 0.25, 1.0));
+}
+
+#[test]
+fn functions_modulo() {
+    assert_eq!(modulo(5.5, 2.0), 1.5);
+    assert_eq!(modulo(-5.5, 2.0), 0.5);
+    assert_eq!(modulo(5.5, -2.0), -0.5);
+    assert_eq!(modulo(Vector2::new(-1.0, 7.0), Vector2::new(3.0, 4.0)), Vector2::new(2.0, 3.0));
+}
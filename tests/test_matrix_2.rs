@@ -0,0 +1,216 @@
+use iomath::matrices::Matrix2;
+use iomath::vectors::Vector2;
+use iomath::types::basic_types::{ TMatrix2, TVector2 };
+
+#[test]
+fn matrix_2_empty() {
+    let matrix = Matrix2::empty();
+
+    assert_eq!(matrix, Matrix2 { x: Vector2::new(0.0, 0.0), y: Vector2::new(0.0, 0.0) });
+}
+
+#[test]
+fn matrix_2_identity() {
+    let matrix = Matrix2::identity();
+
+    assert_eq!(matrix, Matrix2 { x: Vector2::new(1.0, 0.0), y: Vector2::new(0.0, 1.0) });
+}
+
+#[test]
+fn matrix_2_new() {
+    let matrix = Matrix2::new(Vector2::new(1.5, -2.0), Vector2::new(0.5, 3.0));
+
+    assert_eq!(matrix, Matrix2 { x: Vector2::new(1.5, -2.0), y: Vector2::new(0.5, 3.0) });
+}
+
+#[test]
+fn matrix_2_from_diagonal() {
+    let matrix = Matrix2::from_diagonal(Vector2::new(-1.0, 4.0));
+
+    assert_eq!(matrix, Matrix2::new(Vector2::new(-1.0, 0.0), Vector2::new(0.0, 4.0)));
+}
+
+#[test]
+fn matrix_2_copy() {
+    let matrix_from = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    let matrix_to = matrix_from;
+
+    assert_eq!(matrix_from, Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)));
+    assert_eq!(matrix_to, Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)));
+}
+
+#[test]
+fn matrix_2_index() {
+    let matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+
+    assert_eq!(matrix[0], Vector2::new(1.0, 2.0));
+    assert_eq!(matrix[1], Vector2::new(3.0, 4.0));
+    assert_eq!(matrix[1][0], 3.0);
+}
+
+#[test]
+#[should_panic]
+fn matrix_2_index_out_of_bounds() {
+    let matrix = Matrix2::identity();
+
+    let _ = matrix[2];
+}
+
+#[test]
+fn matrix_2_index_mut() {
+    let mut matrix = Matrix2::identity();
+    matrix[1] = Vector2::new(5.0, 6.0);
+    matrix[0][1] = 7.0;
+
+    assert_eq!(matrix, Matrix2::new(Vector2::new(1.0, 7.0), Vector2::new(5.0, 6.0)));
+}
+
+#[test]
+fn matrix_2_transpose() {
+    let matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+
+    assert_eq!(matrix.transpose(), Matrix2::new(Vector2::new(1.0, 3.0), Vector2::new(2.0, 4.0)));
+}
+
+#[test]
+fn matrix_2_determinant() {
+    let matrix = Matrix2::new(Vector2::new(3.0, 5.0), Vector2::new(1.0, 2.0));
+
+    assert_eq!(matrix.determinant(), 1.0);
+}
+
+#[test]
+fn matrix_2_inverse() {
+    let matrix = Matrix2::new(Vector2::new(3.0, 5.0), Vector2::new(1.0, 2.0));
+    let inverse = matrix.inverse().unwrap();
+
+    assert_eq!(inverse, Matrix2::new(Vector2::new(2.0, -5.0), Vector2::new(-1.0, 3.0)));
+    assert_eq!(matrix * inverse, Matrix2::identity());
+}
+
+#[test]
+fn matrix_2_inverse_singular() {
+    let matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(2.0, 4.0));
+
+    assert_eq!(matrix.inverse(), None);
+}
+
+#[test]
+fn matrix_2_inverse_integer() {
+    let matrix = TMatrix2::new(TVector2::new(3, 5), TVector2::new(1, 2));
+    let scaled = TMatrix2::new(TVector2::new(2, 0), TVector2::new(0, 2));
+
+    assert_eq!(matrix.inverse(), Some(TMatrix2::new(TVector2::new(2, -5), TVector2::new(-1, 3))));
+    assert_eq!(scaled.inverse(), None);
+}
+
+
+#[test]
+fn matrix_2_add_matrix() {
+    let matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    let result = matrix + Matrix2::identity();
+
+    assert_eq!(result, Matrix2::new(Vector2::new(2.0, 2.0), Vector2::new(3.0, 5.0)));
+}
+
+#[test]
+fn matrix_2_add_assign_matrix() {
+    let mut matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    matrix += Matrix2::identity();
+
+    assert_eq!(matrix, Matrix2::new(Vector2::new(2.0, 2.0), Vector2::new(3.0, 5.0)));
+}
+
+#[test]
+fn matrix_2_sub_matrix() {
+    let matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    let result = matrix - Matrix2::identity();
+
+    assert_eq!(result, Matrix2::new(Vector2::new(0.0, 2.0), Vector2::new(3.0, 3.0)));
+}
+
+#[test]
+fn matrix_2_sub_assign_matrix() {
+    let mut matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    matrix -= Matrix2::identity();
+
+    assert_eq!(matrix, Matrix2::new(Vector2::new(0.0, 2.0), Vector2::new(3.0, 3.0)));
+}
+
+#[test]
+fn matrix_2_mul_scalar() {
+    let matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    let result = matrix * 2.0;
+
+    assert_eq!(result, Matrix2::new(Vector2::new(2.0, 4.0), Vector2::new(6.0, 8.0)));
+}
+
+#[test]
+fn matrix_2_mul_assign_scalar() {
+    let mut matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    matrix *= 2.0;
+
+    assert_eq!(matrix, Matrix2::new(Vector2::new(2.0, 4.0), Vector2::new(6.0, 8.0)));
+}
+
+#[test]
+fn matrix_2_mul_vector() {
+    let matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    let result = matrix * Vector2::new(2.0, -1.0);
+
+    assert_eq!(result, Vector2::new(-1.0, 0.0));
+}
+
+#[test]
+fn vector_2_mul_matrix() {
+    let matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    let result = Vector2::new(2.0, -1.0) * matrix;
+
+    assert_eq!(result, Vector2::new(0.0, 2.0));
+}
+
+#[test]
+fn matrix_2_mul_matrix() {
+    let matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    let result = matrix * Matrix2::new(Vector2::new(0.0, 1.0), Vector2::new(-1.0, 2.0));
+
+    assert_eq!(result, Matrix2::new(Vector2::new(3.0, 4.0), Vector2::new(5.0, 6.0)));
+}
+
+#[test]
+fn matrix_2_mul_assign_matrix() {
+    let mut matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    matrix *= Matrix2::new(Vector2::new(0.0, 1.0), Vector2::new(-1.0, 2.0));
+
+    assert_eq!(matrix, Matrix2::new(Vector2::new(3.0, 4.0), Vector2::new(5.0, 6.0)));
+}
+
+#[test]
+fn matrix_2_div_scalar() {
+    let matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    let result = matrix / 2.0;
+
+    assert_eq!(result, Matrix2::new(Vector2::new(0.5, 1.0), Vector2::new(1.5, 2.0)));
+}
+
+#[test]
+fn matrix_2_div_assign_scalar() {
+    let mut matrix = Matrix2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    matrix /= 2.0;
+
+    assert_eq!(matrix, Matrix2::new(Vector2::new(0.5, 1.0), Vector2::new(1.5, 2.0)));
+}
+
+#[test]
+fn matrix_2_neg() {
+    let matrix = Matrix2::new(Vector2::new(1.0, -2.0), Vector2::new(3.0, -4.0));
+
+    assert_eq!(-matrix, Matrix2::new(Vector2::new(-1.0, 2.0), Vector2::new(-3.0, 4.0)));
+}
+
+#[test]
+fn matrix_2_debug_struct() {
+    let matrix = Matrix2::identity();
+
+    assert_eq!(format!("{:?}", matrix), "TMatrix2<f32> { x: TVector2<f32> { x: 1.0, y: 0.0 }, y: TVector2<f32> { x: 0.0, y: 1.0 } }");
+}
@@ -0,0 +1,255 @@
+use iomath::matrices::{ Matrix3, Matrix4 };
+use iomath::vectors::{ Vector3, Vector4 };
+use iomath::quaternions::{ Quaternion, EulerOrder };
+use iomath::types::basic_types::{ TMatrix3, TVector3 };
+
+fn sample() -> Matrix3 {
+    Matrix3::new(Vector3::new(1.0, 2.0, 0.0), Vector3::new(0.0, 1.0, 2.0), Vector3::new(1.0, 0.0, 1.0))
+}
+
+#[test]
+fn matrix_3_empty() {
+    let matrix = Matrix3::empty();
+
+    assert_eq!(matrix, Matrix3 { x: Vector3::empty(), y: Vector3::empty(), z: Vector3::empty() });
+}
+
+#[test]
+fn matrix_3_identity() {
+    let matrix = Matrix3::identity();
+
+    assert_eq!(matrix, Matrix3 {
+        x: Vector3::new(1.0, 0.0, 0.0),
+        y: Vector3::new(0.0, 1.0, 0.0),
+        z: Vector3::new(0.0, 0.0, 1.0)
+    });
+}
+
+#[test]
+fn matrix_3_new() {
+    let matrix = Matrix3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0), Vector3::new(7.0, 8.0, 9.0));
+
+    assert_eq!(matrix, Matrix3 {
+        x: Vector3::new(1.0, 2.0, 3.0),
+        y: Vector3::new(4.0, 5.0, 6.0),
+        z: Vector3::new(7.0, 8.0, 9.0)
+    });
+}
+
+#[test]
+fn matrix_3_from_diagonal() {
+    let matrix = Matrix3::from_diagonal(Vector3::new(2.0, -3.0, 0.5));
+
+    assert_eq!(matrix, Matrix3::new(Vector3::new(2.0, 0.0, 0.0), Vector3::new(0.0, -3.0, 0.0), Vector3::new(0.0, 0.0, 0.5)));
+}
+
+#[test]
+fn matrix_3_copy() {
+    let matrix_from = sample();
+    let matrix_to = matrix_from;
+
+    assert_eq!(matrix_from, sample());
+    assert_eq!(matrix_to, sample());
+}
+
+#[test]
+fn matrix_3_index() {
+    let matrix = sample();
+
+    assert_eq!(matrix, Matrix3::new(matrix[0], matrix[1], matrix[2]));
+    assert_eq!(matrix[1][2], 2.0);
+}
+
+#[test]
+#[should_panic]
+fn matrix_3_index_out_of_bounds() {
+    let matrix = sample();
+
+    let _ = matrix[3];
+}
+
+#[test]
+fn matrix_3_index_mut() {
+    let mut matrix = Matrix3::identity();
+    matrix[2] = Vector3::new(4.0, 5.0, 6.0);
+    matrix[0][1] = 7.0;
+
+    assert_eq!(matrix, Matrix3::new(Vector3::new(1.0, 7.0, 0.0), Vector3::new(0.0, 1.0, 0.0), Vector3::new(4.0, 5.0, 6.0)));
+}
+
+#[test]
+fn matrix_3_transpose() {
+    let matrix = sample();
+
+    assert_eq!(matrix.transpose(), Matrix3::new(Vector3::new(1.0, 0.0, 1.0), Vector3::new(2.0, 1.0, 0.0), Vector3::new(0.0, 2.0, 1.0)));
+}
+
+#[test]
+fn matrix_3_determinant() {
+    let matrix = sample();
+
+    assert_eq!(matrix.determinant(), 5.0);
+}
+
+#[test]
+fn matrix_3_inverse() {
+    let matrix = Matrix3::new(Vector3::new(1.0, 1.0, 0.0), Vector3::new(0.0, 1.0, 1.0), Vector3::new(1.0, 0.0, 1.0));
+    let inverse = matrix.inverse().unwrap();
+
+    assert_eq!(inverse, Matrix3::new(Vector3::new(0.5, -0.5, 0.5), Vector3::new(0.5, 0.5, -0.5), Vector3::new(-0.5, 0.5, 0.5)));
+    assert_eq!(matrix * inverse, Matrix3::identity());
+}
+
+#[test]
+fn matrix_3_inverse_singular() {
+    let matrix = Matrix3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0), Vector3::new(7.0, 8.0, 9.0));
+
+    assert_eq!(matrix.inverse(), None);
+}
+
+#[test]
+fn matrix_3_inverse_integer() {
+    let matrix = TMatrix3::new(TVector3::new(1, 2, 0), TVector3::new(0, 1, 0), TVector3::new(0, 3, -1));
+    let inverse = matrix.inverse().unwrap();
+
+    assert_eq!(inverse, TMatrix3::new(TVector3::new(1, -2, 0), TVector3::new(0, 1, 0), TVector3::new(0, 3, -1)));
+    assert_eq!(matrix * inverse, TMatrix3::identity());
+    assert_eq!(TMatrix3::from_diagonal(TVector3::new(1, 3, 1)).inverse(), None);
+}
+
+
+#[test]
+fn matrix_3_add_matrix() {
+    let result = sample() + Matrix3::identity();
+
+    assert_eq!(result, Matrix3::new(Vector3::new(2.0, 2.0, 0.0), Vector3::new(0.0, 2.0, 2.0), Vector3::new(1.0, 0.0, 2.0)));
+}
+
+#[test]
+fn matrix_3_add_assign_matrix() {
+    let mut matrix = sample();
+    matrix += Matrix3::identity();
+
+    assert_eq!(matrix, Matrix3::new(Vector3::new(2.0, 2.0, 0.0), Vector3::new(0.0, 2.0, 2.0), Vector3::new(1.0, 0.0, 2.0)));
+}
+
+#[test]
+fn matrix_3_sub_matrix() {
+    let result = sample() - Matrix3::identity();
+
+    assert_eq!(result, Matrix3::new(Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 0.0, 2.0), Vector3::new(1.0, 0.0, 0.0)));
+}
+
+#[test]
+fn matrix_3_sub_assign_matrix() {
+    let mut matrix = sample();
+    matrix -= Matrix3::identity();
+
+    assert_eq!(matrix, Matrix3::new(Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 0.0, 2.0), Vector3::new(1.0, 0.0, 0.0)));
+}
+
+#[test]
+fn matrix_3_mul_scalar() {
+    let result = sample() * 3.0;
+
+    assert_eq!(result, Matrix3::new(Vector3::new(3.0, 6.0, 0.0), Vector3::new(0.0, 3.0, 6.0), Vector3::new(3.0, 0.0, 3.0)));
+}
+
+#[test]
+fn matrix_3_mul_assign_scalar() {
+    let mut matrix = sample();
+    matrix *= 3.0;
+
+    assert_eq!(matrix, Matrix3::new(Vector3::new(3.0, 6.0, 0.0), Vector3::new(0.0, 3.0, 6.0), Vector3::new(3.0, 0.0, 3.0)));
+}
+
+#[test]
+fn matrix_3_mul_vector() {
+    let result = sample() * Vector3::new(1.0, -1.0, 2.0);
+
+    assert_eq!(result, Vector3::new(3.0, 1.0, 0.0));
+}
+
+#[test]
+fn vector_3_mul_matrix() {
+    let result = Vector3::new(1.0, -1.0, 2.0) * sample();
+
+    assert_eq!(result, Vector3::new(-1.0, 3.0, 3.0));
+}
+
+#[test]
+fn matrix_3_mul_matrix() {
+    let result = sample() * Matrix3::new(Vector3::new(2.0, 0.0, 1.0), Vector3::new(1.0, 1.0, 0.0), Vector3::new(0.0, 3.0, 1.0));
+
+    assert_eq!(result, Matrix3::new(Vector3::new(3.0, 4.0, 1.0), Vector3::new(1.0, 3.0, 2.0), Vector3::new(1.0, 3.0, 7.0)));
+}
+
+#[test]
+fn matrix_3_mul_assign_matrix() {
+    let mut matrix = sample();
+    matrix *= Matrix3::new(Vector3::new(2.0, 0.0, 1.0), Vector3::new(1.0, 1.0, 0.0), Vector3::new(0.0, 3.0, 1.0));
+
+    assert_eq!(matrix, Matrix3::new(Vector3::new(3.0, 4.0, 1.0), Vector3::new(1.0, 3.0, 2.0), Vector3::new(1.0, 3.0, 7.0)));
+}
+
+#[test]
+fn matrix_3_div_scalar() {
+    let result = sample() / 2.0;
+
+    assert_eq!(result, Matrix3::new(Vector3::new(0.5, 1.0, 0.0), Vector3::new(0.0, 0.5, 1.0), Vector3::new(0.5, 0.0, 0.5)));
+}
+
+#[test]
+fn matrix_3_div_assign_scalar() {
+    let mut matrix = sample();
+    matrix /= 2.0;
+
+    assert_eq!(matrix, Matrix3::new(Vector3::new(0.5, 1.0, 0.0), Vector3::new(0.0, 0.5, 1.0), Vector3::new(0.5, 0.0, 0.5)));
+}
+
+#[test]
+fn matrix_3_neg() {
+    let matrix = Matrix3::identity();
+
+    assert_eq!(-matrix, Matrix3::from_diagonal(Vector3::new(-1.0, -1.0, -1.0)));
+}
+
+#[test]
+fn matrix_3_debug_struct() {
+    let matrix = Matrix3::identity();
+
+    assert_eq!(format!("{:?}", matrix), "TMatrix3<f32> { \
+        x: TVector3<f32> { x: 1.0, y: 0.0, z: 0.0 }, \
+        y: TVector3<f32> { x: 0.0, y: 1.0, z: 0.0 }, \
+        z: TVector3<f32> { x: 0.0, y: 0.0, z: 1.0 } }");
+}
+
+#[test]
+fn matrix_3_from_matrix_4() {
+    let matrix = Matrix3::from(Matrix4::new(
+        Vector4::new(1.0, 2.0, 3.0, 4.0),
+        Vector4::new(5.0, 6.0, 7.0, 8.0),
+        Vector4::new(9.0, 10.0, 11.0, 12.0),
+        Vector4::new(13.0, 14.0, 15.0, 16.0)
+    ));
+
+    assert_eq!(matrix, Matrix3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(5.0, 6.0, 7.0), Vector3::new(9.0, 10.0, 11.0)));
+}
+
+#[test]
+fn matrix_3_from_quaternion() {
+    let matrix = Matrix3::from(Quaternion::new(0.0, 0.0, 0.0, 1.0));
+
+    assert_eq!(matrix, Matrix3::from_diagonal(Vector3::new(-1.0, -1.0, 1.0)));
+    assert_eq!(Matrix3::from(Quaternion::identity()), Matrix3::identity());
+}
+
+#[test]
+fn matrix_3_from_quaternion_rotates_as_quaternion() {
+    let quaternion = Quaternion::from_euler(EulerOrder::ZYX, 0.4, -1.1, 2.3);
+    let matrix = Matrix3::from(quaternion);
+
+    for &vector in [Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0), Vector3::new(-2.0, 0.5, 3.0)].iter() {
+        assert!((matrix * vector).distance(quaternion * vector) < 1e-6);
+    }
+}
@@ -0,0 +1,731 @@
+use iomath::matrices::{ Matrix3, Matrix4 };
+use iomath::vectors::{ Vector3, Vector4 };
+use iomath::quaternions::Quaternion;
+use iomath::types::basic_types::{ TMatrix4, TVector4 };
+use iomath::extended::matrices::F64Matrix4;
+use iomath::extended::vectors::F64Vector3;
+
+fn sample() -> Matrix4 {
+    Matrix4::new(
+        Vector4::new(1.0, 2.0, 0.0, 1.0),
+        Vector4::new(0.0, 1.0, 3.0, 0.0),
+        Vector4::new(2.0, 0.0, 1.0, 1.0),
+        Vector4::new(1.0, 1.0, 0.0, 2.0)
+    )
+}
+
+#[test]
+fn matrix_4_empty() {
+    let matrix = Matrix4::empty();
+
+    assert_eq!(matrix, Matrix4 { x: Vector4::empty(), y: Vector4::empty(), z: Vector4::empty(), w: Vector4::empty() });
+}
+
+#[test]
+fn matrix_4_identity() {
+    let matrix = Matrix4::identity();
+
+    assert_eq!(matrix, Matrix4 {
+        x: Vector4::new(1.0, 0.0, 0.0, 0.0),
+        y: Vector4::new(0.0, 1.0, 0.0, 0.0),
+        z: Vector4::new(0.0, 0.0, 1.0, 0.0),
+        w: Vector4::new(0.0, 0.0, 0.0, 1.0)
+    });
+}
+
+#[test]
+fn matrix_4_new() {
+    let matrix = sample();
+
+    assert_eq!(matrix, Matrix4 {
+        x: Vector4::new(1.0, 2.0, 0.0, 1.0),
+        y: Vector4::new(0.0, 1.0, 3.0, 0.0),
+        z: Vector4::new(2.0, 0.0, 1.0, 1.0),
+        w: Vector4::new(1.0, 1.0, 0.0, 2.0)
+    });
+}
+
+#[test]
+fn matrix_4_from_diagonal() {
+    let matrix = Matrix4::from_diagonal(Vector4::new(2.0, -3.0, 0.5, 1.0));
+
+    assert_eq!(matrix, Matrix4::new(
+        Vector4::new(2.0, 0.0, 0.0, 0.0),
+        Vector4::new(0.0, -3.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.5, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0)
+    ));
+}
+
+#[test]
+fn matrix_4_copy() {
+    let matrix_from = sample();
+    let matrix_to = matrix_from;
+
+    assert_eq!(matrix_from, sample());
+    assert_eq!(matrix_to, sample());
+}
+
+#[test]
+fn matrix_4_index() {
+    let matrix = sample();
+
+    assert_eq!(matrix, Matrix4::new(matrix[0], matrix[1], matrix[2], matrix[3]));
+    assert_eq!(matrix[1][2], 3.0);
+}
+
+#[test]
+#[should_panic]
+fn matrix_4_index_out_of_bounds() {
+    let matrix = sample();
+
+    let _ = matrix[4];
+}
+
+#[test]
+fn matrix_4_index_mut() {
+    let mut matrix = Matrix4::identity();
+    matrix[3] = Vector4::new(4.0, 5.0, 6.0, 1.0);
+    matrix[0][1] = 7.0;
+
+    assert_eq!(matrix, Matrix4::new(
+        Vector4::new(1.0, 7.0, 0.0, 0.0),
+        Vector4::new(0.0, 1.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 1.0, 0.0),
+        Vector4::new(4.0, 5.0, 6.0, 1.0)
+    ));
+}
+
+#[test]
+fn matrix_4_transpose() {
+    let matrix = sample();
+
+    assert_eq!(matrix.transpose(), Matrix4::new(
+        Vector4::new(1.0, 0.0, 2.0, 1.0),
+        Vector4::new(2.0, 1.0, 0.0, 1.0),
+        Vector4::new(0.0, 3.0, 1.0, 0.0),
+        Vector4::new(1.0, 0.0, 1.0, 2.0)
+    ));
+}
+
+#[test]
+fn matrix_4_determinant() {
+    let matrix = sample();
+
+    assert_eq!(matrix.determinant(), 16.0);
+    assert_eq!(matrix.transpose().determinant(), 16.0);
+}
+
+#[test]
+fn matrix_4_inverse() {
+    let matrix = sample();
+    let inverse = matrix.inverse().unwrap();
+
+    assert_eq!(inverse, Matrix4::new(
+        Vector4::new(5.0, -3.0, 9.0, -7.0),
+        Vector4::new(9.0, 1.0, -3.0, -3.0),
+        Vector4::new(-3.0, 5.0, 1.0, 1.0),
+        Vector4::new(-7.0, 1.0, -3.0, 13.0)
+    ) / 16.0);
+    assert_eq!(matrix * inverse, Matrix4::identity());
+}
+
+#[test]
+fn matrix_4_inverse_singular() {
+    let mut matrix = sample();
+    matrix.w = matrix.x + matrix.y;
+
+    assert_eq!(matrix.inverse(), None);
+}
+
+#[test]
+fn matrix_4_inverse_integer() {
+    let matrix = TMatrix4::new(TVector4::new(0, 1, 0, 0), TVector4::new(1, 0, 0, 0), TVector4::new(0, 0, 1, 0), TVector4::new(2, 3, 4, 1));
+    let inverse = matrix.inverse().unwrap();
+
+    assert_eq!(matrix * inverse, TMatrix4::identity());
+    assert_eq!(inverse.w, TVector4::new(-3, -2, -4, 1));
+    assert_eq!(TMatrix4::from_diagonal(TVector4::new(2, 1, 1, 1)).inverse(), None);
+}
+
+
+#[test]
+fn matrix_4_add_matrix() {
+    let result = sample() + Matrix4::identity();
+
+    assert_eq!(result, Matrix4::new(
+        Vector4::new(2.0, 2.0, 0.0, 1.0),
+        Vector4::new(0.0, 2.0, 3.0, 0.0),
+        Vector4::new(2.0, 0.0, 2.0, 1.0),
+        Vector4::new(1.0, 1.0, 0.0, 3.0)
+    ));
+}
+
+#[test]
+fn matrix_4_add_assign_matrix() {
+    let mut matrix = sample();
+    matrix += Matrix4::identity();
+
+    assert_eq!(matrix, sample() + Matrix4::identity());
+}
+
+#[test]
+fn matrix_4_sub_matrix() {
+    let result = sample() - Matrix4::identity();
+
+    assert_eq!(result, Matrix4::new(
+        Vector4::new(0.0, 2.0, 0.0, 1.0),
+        Vector4::new(0.0, 0.0, 3.0, 0.0),
+        Vector4::new(2.0, 0.0, 0.0, 1.0),
+        Vector4::new(1.0, 1.0, 0.0, 1.0)
+    ));
+}
+
+#[test]
+fn matrix_4_sub_assign_matrix() {
+    let mut matrix = sample();
+    matrix -= Matrix4::identity();
+
+    assert_eq!(matrix, sample() - Matrix4::identity());
+}
+
+#[test]
+fn matrix_4_mul_scalar() {
+    let result = Matrix4::identity() * 3.0;
+
+    assert_eq!(result, Matrix4::from_diagonal(Vector4::from_scalar(3.0)));
+}
+
+#[test]
+fn matrix_4_mul_assign_scalar() {
+    let mut matrix = Matrix4::identity();
+    matrix *= 3.0;
+
+    assert_eq!(matrix, Matrix4::from_diagonal(Vector4::from_scalar(3.0)));
+}
+
+#[test]
+fn matrix_4_mul_vector() {
+    let result = sample() * Vector4::new(1.0, -1.0, 2.0, 0.5);
+
+    assert_eq!(result, Vector4::new(5.5, 1.5, -1.0, 4.0));
+}
+
+#[test]
+fn vector_4_mul_matrix() {
+    let result = Vector4::new(1.0, -1.0, 2.0, 0.5) * sample();
+
+    assert_eq!(result, Vector4::new(-0.5, 5.0, 4.5, 1.0));
+}
+
+#[test]
+fn matrix_4_mul_matrix() {
+    let other = Matrix4::new(
+        Vector4::new(1.0, 0.0, 0.0, 1.0),
+        Vector4::new(0.0, 2.0, 0.0, 0.0),
+        Vector4::new(1.0, 0.0, 1.0, 0.0),
+        Vector4::new(0.0, 1.0, 0.0, 1.0)
+    );
+    let result = sample() * other;
+
+    assert_eq!(result, Matrix4::new(
+        Vector4::new(2.0, 3.0, 0.0, 3.0),
+        Vector4::new(0.0, 2.0, 6.0, 0.0),
+        Vector4::new(3.0, 2.0, 1.0, 2.0),
+        Vector4::new(1.0, 2.0, 3.0, 2.0)
+    ));
+}
+
+#[test]
+fn matrix_4_mul_assign_matrix() {
+    let mut matrix = sample();
+    matrix *= matrix.inverse().unwrap();
+
+    assert_eq!(matrix, Matrix4::identity());
+}
+
+#[test]
+fn matrix_4_div_scalar() {
+    let result = Matrix4::identity() / 4.0;
+
+    assert_eq!(result, Matrix4::from_diagonal(Vector4::from_scalar(0.25)));
+}
+
+#[test]
+fn matrix_4_div_assign_scalar() {
+    let mut matrix = Matrix4::identity();
+    matrix /= 4.0;
+
+    assert_eq!(matrix, Matrix4::from_diagonal(Vector4::from_scalar(0.25)));
+}
+
+#[test]
+fn matrix_4_neg() {
+    let matrix = Matrix4::identity();
+
+    assert_eq!(-matrix, Matrix4::from_diagonal(Vector4::from_scalar(-1.0)));
+}
+
+#[test]
+fn matrix_4_debug_struct() {
+    let matrix = Matrix4::identity();
+
+    assert_eq!(format!("{:?}", matrix), "TMatrix4<f32> { \
+        x: TVector4<f32> { x: 1.0, y: 0.0, z: 0.0, w: 0.0 }, \
+        y: TVector4<f32> { x: 0.0, y: 1.0, z: 0.0, w: 0.0 }, \
+        z: TVector4<f32> { x: 0.0, y: 0.0, z: 1.0, w: 0.0 }, \
+        w: TVector4<f32> { x: 0.0, y: 0.0, z: 0.0, w: 1.0 } }");
+}
+
+#[test]
+fn matrix_4_decomposition_debug_struct() {
+    let decomposition = Matrix4::identity().decompose().unwrap();
+
+    assert_eq!(format!("{:?}", decomposition), "TDecomposition<f32> { \
+        scale: TVector3<f32> { x: 1.0, y: 1.0, z: 1.0 }, \
+        rotation: TQuaternion<f32> { w: 1.0, x: 0.0, y: 0.0, z: 0.0 }, \
+        translation: TVector3<f32> { x: 0.0, y: 0.0, z: 0.0 }, \
+        skew: TVector3<f32> { x: 0.0, y: 0.0, z: 0.0 }, \
+        perspective: TVector4<f32> { x: 0.0, y: 0.0, z: 0.0, w: 1.0 } }");
+}
+
+#[test]
+fn matrix_4_from_matrix_3() {
+    let matrix = Matrix4::from(Matrix3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0), Vector3::new(7.0, 8.0, 9.0)));
+
+    assert_eq!(matrix, Matrix4::new(
+        Vector4::new(1.0, 2.0, 3.0, 0.0),
+        Vector4::new(4.0, 5.0, 6.0, 0.0),
+        Vector4::new(7.0, 8.0, 9.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0)
+    ));
+}
+
+#[test]
+fn matrix_4_from_quaternion() {
+    let quaternion = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+
+    assert_eq!(Matrix4::from_quaternion(quaternion), Matrix4::from_diagonal(Vector4::new(1.0, -1.0, -1.0, 1.0)));
+    assert_eq!(Matrix4::from(quaternion), Matrix4::from_quaternion(quaternion));
+    assert_eq!(Matrix4::from_quaternion(Quaternion::identity()), Matrix4::identity());
+}
+
+
+#[test]
+fn matrix_4_from_translation() {
+    let matrix = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+
+    assert_eq!(matrix.w, Vector4::new(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(matrix * Vector4::new(1.0, 1.0, 1.0, 1.0), Vector4::new(2.0, 3.0, 4.0, 1.0));
+    assert_eq!(matrix * Vector4::new(1.0, 1.0, 1.0, 0.0), Vector4::new(1.0, 1.0, 1.0, 0.0));
+}
+
+#[test]
+fn matrix_4_from_scale() {
+    assert_eq!(Matrix4::from_scale(Vector3::new(2.0, 3.0, 4.0)), Matrix4::from_diagonal(Vector4::new(2.0, 3.0, 4.0, 1.0)));
+}
+
+#[test]
+fn matrix_4_from_shear() {
+    let matrix = Matrix4::from_shear(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+
+    assert_eq!(matrix.transform_point3(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(4.0, 8.0, 12.0));
+    assert_eq!(matrix.transform_point3(Vector3::new(0.0, 0.0, 2.0)), Vector3::new(4.0, 8.0, 2.0));
+}
+
+#[test]
+fn matrix_4_from_axis_angle() {
+    let matrix = Matrix4::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), std::f32::consts::FRAC_PI_2);
+
+    assert!(matrix.transform_vector3(Vector3::new(0.0, 0.0, 1.0)).distance(Vector3::new(1.0, 0.0, 0.0)) < 1e-6);
+    assert!(matrix.transform_vector3(Vector3::new(0.0, 1.0, 0.0)).distance(Vector3::new(0.0, 1.0, 0.0)) < 1e-6);
+    assert_eq!(matrix.w, Vector4::new(0.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn matrix_4_from_rotation_translation_scale() {
+    let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), std::f32::consts::FRAC_PI_2);
+    let translation = Vector3::new(1.0, 2.0, 3.0);
+    let scale = Vector3::new(2.0, 3.0, 4.0);
+    let matrix = Matrix4::from_rotation_translation_scale(rotation, translation, scale);
+    let expected = Matrix4::from_translation(translation) * Matrix4::from_quaternion(rotation) * Matrix4::from_scale(scale);
+
+    for column in 0..4 {
+        assert!((matrix[column] - expected[column]).length() < 1e-6);
+    }
+    assert!(matrix.transform_point3(Vector3::new(1.0, 0.0, 0.0)).distance(Vector3::new(1.0, 4.0, 3.0)) < 1e-6);
+}
+
+#[test]
+fn matrix_4_translate() {
+    let matrix = Matrix4::from_scale(Vector3::new(2.0, 2.0, 2.0)).translate(Vector3::new(1.0, 2.0, 3.0));
+
+    assert_eq!(matrix, Matrix4::from_scale(Vector3::new(2.0, 2.0, 2.0)) * Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)));
+    assert_eq!(matrix.transform_point3(Vector3::new(0.0, 0.0, 0.0)), Vector3::new(2.0, 4.0, 6.0));
+}
+
+#[test]
+fn matrix_4_scale() {
+    let matrix = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)).scale(Vector3::new(2.0, 3.0, 4.0));
+
+    assert_eq!(matrix.transform_point3(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(3.0, 5.0, 7.0));
+}
+
+#[test]
+fn matrix_4_shear() {
+    let matrix = Matrix4::identity().shear(1.0, 0.0, 0.0, 0.0, 0.0, 0.0).scale(Vector3::new(1.0, 2.0, 1.0));
+
+    assert_eq!(matrix.transform_point3(Vector3::new(0.0, 1.0, 0.0)), Vector3::new(2.0, 2.0, 0.0));
+}
+
+#[test]
+fn matrix_4_rotate() {
+    let matrix = Matrix4::from_translation(Vector3::new(0.0, 0.0, 1.0)).rotate(Vector3::new(1.0, 0.0, 0.0), std::f32::consts::FRAC_PI_2);
+
+    assert!(matrix.transform_point3(Vector3::new(0.0, 1.0, 0.0)).distance(Vector3::new(0.0, 0.0, 2.0)) < 1e-6);
+}
+
+#[test]
+fn matrix_4_look_at_rh() {
+    let eye = Vector3::new(1.0, 2.0, 3.0);
+    let center = Vector3::new(4.0, 0.0, -1.0);
+    let view = Matrix4::look_at_rh(eye, center, Vector3::new(0.0, 1.0, 0.0));
+    let distance = (center - eye).length();
+
+    assert!(view.transform_point3(eye).length() < 1e-6);
+    assert!(view.transform_point3(center).distance(Vector3::new(0.0, 0.0, -distance)) < 1e-5);
+    assert!(view.transform_vector3(Vector3::new(0.0, 1.0, 0.0)).x.abs() < 1e-6);
+}
+
+#[test]
+fn matrix_4_look_at_lh() {
+    let eye = Vector3::new(1.0, 2.0, 3.0);
+    let center = Vector3::new(4.0, 0.0, -1.0);
+    let view = Matrix4::look_at_lh(eye, center, Vector3::new(0.0, 1.0, 0.0));
+    let distance = (center - eye).length();
+
+    assert!(view.transform_point3(eye).length() < 1e-6);
+    assert!(view.transform_point3(center).distance(Vector3::new(0.0, 0.0, distance)) < 1e-5);
+    assert!(view.transform_vector3(Vector3::new(0.0, 1.0, 0.0)).x.abs() < 1e-6);
+}
+
+#[test]
+fn matrix_4_transform_point3() {
+    let matrix = sample();
+
+    assert_eq!(matrix.transform_point3(Vector3::new(1.0, 2.0, 3.0)), Vector3::new(8.0, 5.0, 9.0));
+}
+
+#[test]
+fn matrix_4_transform_vector3() {
+    let matrix = sample();
+
+    assert_eq!(matrix.transform_vector3(Vector3::new(1.0, 2.0, 3.0)), Vector3::new(7.0, 4.0, 9.0));
+}
+
+fn ndc_depth(projection: Matrix4, z: f32) -> f32 {
+    let clip = projection * Vector4::new(0.0, 0.0, z, 1.0);
+    clip.z / clip.w
+}
+
+#[test]
+fn matrix_4_perspective_rh_gl() {
+    let projection = Matrix4::perspective_rh_gl(std::f32::consts::FRAC_PI_2, 2.0, 1.0, 3.0);
+
+    assert_eq!(projection, Matrix4::new(
+        Vector4::new(0.5, 0.0, 0.0, 0.0),
+        Vector4::new(0.0, 1.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, -2.0, -1.0),
+        Vector4::new(0.0, 0.0, -3.0, 0.0)
+    ));
+    assert!((ndc_depth(projection, -1.0) + 1.0).abs() < 1e-6);
+    assert!((ndc_depth(projection, -3.0) - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn matrix_4_perspective_rh_zo() {
+    let projection = Matrix4::perspective_rh_zo(std::f32::consts::FRAC_PI_2, 2.0, 1.0, 3.0);
+
+    assert_eq!(projection.z, Vector4::new(0.0, 0.0, -1.5, -1.0));
+    assert_eq!(projection.w, Vector4::new(0.0, 0.0, -1.5, 0.0));
+    assert!(ndc_depth(projection, -1.0).abs() < 1e-6);
+    assert!((ndc_depth(projection, -3.0) - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn matrix_4_perspective_lh_gl() {
+    let projection = Matrix4::perspective_lh_gl(std::f32::consts::FRAC_PI_2, 2.0, 1.0, 3.0);
+
+    assert_eq!(projection.z, Vector4::new(0.0, 0.0, 2.0, 1.0));
+    assert_eq!(projection.w, Vector4::new(0.0, 0.0, -3.0, 0.0));
+    assert!((ndc_depth(projection, 1.0) + 1.0).abs() < 1e-6);
+    assert!((ndc_depth(projection, 3.0) - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn matrix_4_perspective_lh_zo() {
+    let projection = Matrix4::perspective_lh_zo(std::f32::consts::FRAC_PI_2, 2.0, 1.0, 3.0);
+
+    assert_eq!(projection.z, Vector4::new(0.0, 0.0, 1.5, 1.0));
+    assert_eq!(projection.w, Vector4::new(0.0, 0.0, -1.5, 0.0));
+    assert!(ndc_depth(projection, 1.0).abs() < 1e-6);
+    assert!((ndc_depth(projection, 3.0) - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn matrix_4_perspective_infinite_rh_gl() {
+    let projection = Matrix4::perspective_infinite_rh_gl(std::f32::consts::FRAC_PI_2, 1.0, 0.5);
+
+    assert!((ndc_depth(projection, -0.5) + 1.0).abs() < 1e-6);
+    assert!((ndc_depth(projection, -1e7) - 1.0).abs() < 1e-6);
+    assert!(ndc_depth(projection, -1.0) < ndc_depth(projection, -2.0));
+}
+
+#[test]
+fn matrix_4_perspective_infinite_rh_zo() {
+    let projection = Matrix4::perspective_infinite_rh_zo(std::f32::consts::FRAC_PI_2, 1.0, 0.5);
+
+    assert!(ndc_depth(projection, -0.5).abs() < 1e-6);
+    assert!((ndc_depth(projection, -1e7) - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn matrix_4_perspective_infinite_lh_gl() {
+    let projection = Matrix4::perspective_infinite_lh_gl(std::f32::consts::FRAC_PI_2, 1.0, 0.5);
+
+    assert!((ndc_depth(projection, 0.5) + 1.0).abs() < 1e-6);
+    assert!((ndc_depth(projection, 1e7) - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn matrix_4_perspective_infinite_lh_zo() {
+    let projection = Matrix4::perspective_infinite_lh_zo(std::f32::consts::FRAC_PI_2, 1.0, 0.5);
+
+    assert!(ndc_depth(projection, 0.5).abs() < 1e-6);
+    assert!((ndc_depth(projection, 1e7) - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn matrix_4_perspective_infinite_reverse_rh() {
+    let projection = Matrix4::perspective_infinite_reverse_rh(std::f32::consts::FRAC_PI_2, 1.0, 0.5);
+
+    assert!((ndc_depth(projection, -0.5) - 1.0).abs() < 1e-6);
+    assert!((ndc_depth(projection, -2.0) - 0.25).abs() < 1e-6);
+    assert!(ndc_depth(projection, -1e7).abs() < 1e-6);
+}
+
+#[test]
+fn matrix_4_perspective_infinite_reverse_lh() {
+    let projection = Matrix4::perspective_infinite_reverse_lh(std::f32::consts::FRAC_PI_2, 1.0, 0.5);
+
+    assert!((ndc_depth(projection, 0.5) - 1.0).abs() < 1e-6);
+    assert!((ndc_depth(projection, 2.0) - 0.25).abs() < 1e-6);
+    assert!(ndc_depth(projection, 1e7).abs() < 1e-6);
+}
+
+#[test]
+fn matrix_4_orthographic_rh_gl() {
+    let projection = Matrix4::orthographic_rh_gl(0.0, 4.0, 0.0, 2.0, 1.0, 3.0);
+
+    assert_eq!(projection, Matrix4::new(
+        Vector4::new(0.5, 0.0, 0.0, 0.0),
+        Vector4::new(0.0, 1.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, -1.0, 0.0),
+        Vector4::new(-1.0, -1.0, -2.0, 1.0)
+    ));
+}
+
+#[test]
+fn matrix_4_orthographic_rh_zo() {
+    let projection = Matrix4::orthographic_rh_zo(0.0, 4.0, 0.0, 2.0, 1.0, 3.0);
+
+    assert_eq!(projection * Vector4::new(0.0, 0.0, -1.0, 1.0), Vector4::new(-1.0, -1.0, 0.0, 1.0));
+    assert_eq!(projection * Vector4::new(4.0, 2.0, -3.0, 1.0), Vector4::new(1.0, 1.0, 1.0, 1.0));
+}
+
+#[test]
+fn matrix_4_orthographic_lh_gl() {
+    let projection = Matrix4::orthographic_lh_gl(0.0, 4.0, 0.0, 2.0, 1.0, 3.0);
+
+    assert_eq!(projection * Vector4::new(0.0, 0.0, 1.0, 1.0), Vector4::new(-1.0, -1.0, -1.0, 1.0));
+    assert_eq!(projection * Vector4::new(4.0, 2.0, 3.0, 1.0), Vector4::new(1.0, 1.0, 1.0, 1.0));
+}
+
+#[test]
+fn matrix_4_orthographic_lh_zo() {
+    let projection = Matrix4::orthographic_lh_zo(0.0, 4.0, 0.0, 2.0, 1.0, 3.0);
+
+    assert_eq!(projection * Vector4::new(0.0, 0.0, 1.0, 1.0), Vector4::new(-1.0, -1.0, 0.0, 1.0));
+    assert_eq!(projection * Vector4::new(4.0, 2.0, 3.0, 1.0), Vector4::new(1.0, 1.0, 1.0, 1.0));
+}
+
+#[test]
+fn matrix_4_frustum() {
+    let projection = Matrix4::frustum(-1.0, 3.0, -1.0, 1.0, 1.0, 3.0);
+
+    assert_eq!(projection, Matrix4::new(
+        Vector4::new(0.5, 0.0, 0.0, 0.0),
+        Vector4::new(0.0, 1.0, 0.0, 0.0),
+        Vector4::new(0.5, 0.0, -2.0, -1.0),
+        Vector4::new(0.0, 0.0, -3.0, 0.0)
+    ));
+
+    let corner = projection * Vector4::new(3.0, 1.0, -1.0, 1.0);
+    assert_eq!(corner / corner.w, Vector4::new(1.0, 1.0, -1.0, 1.0));
+}
+
+#[test]
+fn matrix_4_project_gl() {
+    let view = Matrix4::look_at_rh(Vector3::new(0.0, 0.0, 5.0), Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    let projection = Matrix4::perspective_rh_gl(std::f32::consts::FRAC_PI_2, 2.0, 1.0, 9.0);
+    let viewport = Vector4::new(10.0, 20.0, 800.0, 400.0);
+    let window = (projection * view).project_gl(Vector3::new(0.0, 0.0, 0.0), viewport);
+
+    assert!(window.distance(Vector3::new(410.0, 220.0, 0.9)) < 1e-4);
+    assert!((projection * view).project_gl(Vector3::new(0.0, 0.0, 4.0), viewport).z.abs() < 1e-6);
+}
+
+#[test]
+fn matrix_4_project_zo() {
+    let projection = Matrix4::perspective_rh_zo(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 9.0);
+    let viewport = Vector4::new(0.0, 0.0, 100.0, 100.0);
+
+    assert!(projection.project_zo(Vector3::new(1.0, -1.0, -1.0), viewport).distance(Vector3::new(100.0, 0.0, 0.0)) < 1e-4);
+    assert!(projection.project_zo(Vector3::new(0.0, 0.0, -9.0), viewport).distance(Vector3::new(50.0, 50.0, 1.0)) < 1e-4);
+}
+
+#[test]
+fn matrix_4_unproject_gl() {
+    let view = Matrix4::look_at_rh(Vector3::new(1.0, 2.0, 5.0), Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    let matrix = Matrix4::perspective_rh_gl(1.0, 1.5, 0.5, 20.0) * view;
+    let viewport = Vector4::new(0.0, 0.0, 1920.0, 1080.0);
+    let object = Vector3::new(0.5, -0.25, 1.0);
+
+    assert!(matrix.unproject_gl(matrix.project_gl(object, viewport), viewport).unwrap().distance(object) < 1e-4);
+    assert_eq!(Matrix4::from_diagonal(Vector4::new(1.0, 1.0, 0.0, 1.0)).unproject_gl(Vector3::new(0.0, 0.0, 0.0), viewport), None);
+}
+
+#[test]
+fn matrix_4_unproject_zo() {
+    let view = Matrix4::look_at_lh(Vector3::new(1.0, 2.0, -5.0), Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    let matrix = Matrix4::perspective_lh_zo(1.0, 1.5, 0.5, 20.0) * view;
+    let viewport = Vector4::new(0.0, 0.0, 1920.0, 1080.0);
+    let object = Vector3::new(0.5, -0.25, 1.0);
+
+    assert!(matrix.unproject_zo(matrix.project_zo(object, viewport), viewport).unwrap().distance(object) < 1e-4);
+}
+
+fn assert_matrix_near(left: Matrix4, right: Matrix4) {
+    for column in 0..4 {
+        assert!((left[column] - right[column]).length() < 1e-5, "{:?} != {:?}", left, right);
+    }
+}
+
+#[test]
+fn matrix_4_to_scale_rotation_translation() {
+    let rotation = Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 2.0) / 3.0, 2.5);
+    let translation = Vector3::new(-1.0, 4.0, 2.0);
+    let scale = Vector3::new(0.5, 3.0, 2.0);
+    let matrix = Matrix4::from_rotation_translation_scale(rotation, translation, scale);
+    let (found_scale, found_rotation, found_translation) = matrix.to_scale_rotation_translation().unwrap();
+
+    assert!(found_scale.distance(scale) < 1e-5);
+    assert!(found_rotation.dot(rotation).abs() > 1.0 - 1e-6);
+    assert_eq!(found_translation, translation);
+    assert_matrix_near(Matrix4::from_rotation_translation_scale(found_rotation, found_translation, found_scale), matrix);
+}
+
+#[test]
+fn matrix_4_to_scale_rotation_translation_negative_scale() {
+    let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 0.75);
+    let matrix = Matrix4::from_rotation_translation_scale(rotation, Vector3::new(1.0, 2.0, 3.0), Vector3::new(2.0, -3.0, 4.0));
+    let (scale, found_rotation, translation) = matrix.to_scale_rotation_translation().unwrap();
+
+    assert!(scale.x < 0.0 && scale.y > 0.0 && scale.z > 0.0);
+    assert!((scale.abs() - Vector3::new(2.0, 3.0, 4.0)).length() < 1e-5);
+    assert_matrix_near(Matrix4::from_rotation_translation_scale(found_rotation, translation, scale), matrix);
+}
+
+#[test]
+fn matrix_4_to_scale_rotation_translation_failure() {
+    let sheared = Matrix4::from_shear(0.0, 0.0, 0.5, 0.0, 0.0, 0.0);
+    let degenerate = Matrix4::from_scale(Vector3::new(1.0, 0.0, 1.0));
+    let projective = Matrix4::perspective_rh_gl(1.0, 1.0, 1.0, 10.0);
+
+    assert_eq!(sheared.to_scale_rotation_translation(), None);
+    assert_eq!(degenerate.to_scale_rotation_translation(), None);
+    assert_eq!(projective.to_scale_rotation_translation(), None);
+}
+
+#[test]
+fn matrix_4_to_scale_rotation_translation_near_zero_scale() {
+    let matrix = Matrix4::from_scale(Vector3::new(1e-30, 1.0, 1.0));
+
+    assert_eq!(matrix.to_scale_rotation_translation(), None);
+    assert_eq!(matrix.decompose(), None);
+}
+
+#[test]
+fn matrix_4_decompose() {
+    let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), 1.25);
+    let translation = Vector3::new(3.0, -2.0, 1.0);
+    let scale = Vector3::new(2.0, 0.5, 1.5);
+    let shear = Matrix4::from_shear(0.25, -0.5, 0.0, 0.75, 0.0, 0.0);
+    let matrix = Matrix4::from_translation(translation) * Matrix4::from_quaternion(rotation) * shear * Matrix4::from_scale(scale);
+    let decomposition = matrix.decompose().unwrap();
+
+    assert!(decomposition.scale.distance(scale) < 1e-5);
+    assert!(decomposition.rotation.dot(rotation).abs() > 1.0 - 1e-6);
+    assert!(decomposition.translation.distance(translation) < 1e-6);
+    assert!(decomposition.skew.distance(Vector3::new(0.75, -0.5, 0.25)) < 1e-5);
+    assert_eq!(decomposition.perspective, Vector4::new(0.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn matrix_4_decompose_perspective() {
+    let mut perspective = Matrix4::identity();
+    perspective.x.w = 0.75;
+    perspective.z.w = -0.25;
+    let affine = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)).scale(Vector3::new(2.0, 2.0, 2.0));
+    let decomposition = (perspective * affine).decompose().unwrap();
+
+    assert!((decomposition.perspective - Vector4::new(0.75, 0.0, -0.25, 1.0)).length() < 1e-5);
+    assert!(decomposition.translation.distance(Vector3::new(1.0, 2.0, 3.0)) < 1e-5);
+    assert!(decomposition.scale.distance(Vector3::new(2.0, 2.0, 2.0)) < 1e-5);
+}
+
+#[test]
+fn matrix_4_decompose_mirrored() {
+    let decomposition = Matrix4::from_scale(Vector3::new(1.0, 2.0, -3.0)).decompose().unwrap();
+    let rebuilt = Matrix4::from_quaternion(decomposition.rotation) * Matrix4::from_scale(decomposition.scale);
+
+    assert_eq!(decomposition.scale, Vector3::new(-1.0, -2.0, -3.0));
+    assert_matrix_near(rebuilt, Matrix4::from_scale(Vector3::new(1.0, 2.0, -3.0)));
+}
+
+#[test]
+fn matrix_4_decompose_degenerate() {
+    let mut zero_w = Matrix4::identity();
+    zero_w.w.w = 0.0;
+
+    assert_eq!(zero_w.decompose(), None);
+    assert_eq!(Matrix4::from_scale(Vector3::new(1.0, 1.0, 0.0)).decompose(), None);
+
+    let nearly_collinear = Matrix4::new(
+        Vector4::new(1.0, 0.0, 0.0, 0.0),
+        Vector4::new(1.0, 1e-9, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 1.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0)
+    );
+    assert_eq!(nearly_collinear.decompose(), None);
+}
+
+#[test]
+fn matrix_4_decompose_small_scale() {
+    let decomposition = Matrix4::from_scale(Vector3::from_scalar(0.001)).decompose().unwrap();
+    assert!(decomposition.scale.distance(Vector3::from_scalar(0.001)) < 1e-9);
+    assert_eq!(decomposition.rotation, Quaternion::identity());
+
+    let decomposition = F64Matrix4::from_scale(F64Vector3::from_scalar(0.001)).decompose().unwrap();
+    assert!(decomposition.scale.distance(F64Vector3::from_scalar(0.001)) < 1e-15);
+}
\ No newline at end of file
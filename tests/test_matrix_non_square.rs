@@ -0,0 +1,280 @@
+use iomath::matrices::{ Matrix2, Matrix3, Matrix4, Matrix2x3, Matrix2x4, Matrix3x2, Matrix3x4, Matrix4x2, Matrix4x3 };
+use iomath::vectors::{ Vector2, Vector3, Vector4 };
+
+fn sample_2x3() -> Matrix2x3 {
+    Matrix2x3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0))
+}
+
+fn sample_3x2() -> Matrix3x2 {
+    Matrix3x2::new(Vector2::new(1.0, 0.0), Vector2::new(2.0, -1.0), Vector2::new(0.0, 3.0))
+}
+
+fn sample_4x3() -> Matrix4x3 {
+    Matrix4x3::new(
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 2.0, 0.0),
+        Vector3::new(0.0, 0.0, 3.0),
+        Vector3::new(4.0, 5.0, 6.0)
+    )
+}
+
+#[test]
+fn matrix_2x3_empty() {
+    let matrix = Matrix2x3::empty();
+
+    assert_eq!(matrix, Matrix2x3 { x: Vector3::empty(), y: Vector3::empty() });
+}
+
+#[test]
+fn matrix_2x3_identity() {
+    let matrix = Matrix2x3::identity();
+
+    assert_eq!(matrix, Matrix2x3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0)));
+}
+
+#[test]
+fn matrix_4x2_identity() {
+    let matrix = Matrix4x2::identity();
+
+    assert_eq!(matrix, Matrix4x2::new(Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0), Vector2::empty(), Vector2::empty()));
+}
+
+#[test]
+fn matrix_2x3_new() {
+    let matrix = sample_2x3();
+
+    assert_eq!(matrix, Matrix2x3 { x: Vector3::new(1.0, 2.0, 3.0), y: Vector3::new(4.0, 5.0, 6.0) });
+}
+
+#[test]
+fn matrix_2x3_copy() {
+    let matrix_from = sample_2x3();
+    let matrix_to = matrix_from;
+
+    assert_eq!(matrix_from, sample_2x3());
+    assert_eq!(matrix_to, sample_2x3());
+}
+
+#[test]
+fn matrix_2x3_index() {
+    let matrix = sample_2x3();
+
+    assert_eq!(matrix[1], Vector3::new(4.0, 5.0, 6.0));
+    assert_eq!(matrix[0][2], 3.0);
+}
+
+#[test]
+#[should_panic]
+fn matrix_2x3_index_out_of_bounds() {
+    let matrix = sample_2x3();
+
+    let _ = matrix[2];
+}
+
+#[test]
+fn matrix_4x3_index_mut() {
+    let mut matrix = sample_4x3();
+    matrix[3] = Vector3::new(-1.0, -2.0, -3.0);
+    matrix[0][1] = 9.0;
+
+    assert_eq!(matrix.w, Vector3::new(-1.0, -2.0, -3.0));
+    assert_eq!(matrix.x, Vector3::new(1.0, 9.0, 0.0));
+}
+
+#[test]
+fn matrix_2x3_transpose() {
+    let matrix = sample_2x3();
+
+    assert_eq!(matrix.transpose(), Matrix3x2::new(Vector2::new(1.0, 4.0), Vector2::new(2.0, 5.0), Vector2::new(3.0, 6.0)));
+    assert_eq!(matrix.transpose().transpose(), matrix);
+}
+
+#[test]
+fn matrix_4x3_transpose() {
+    let matrix = sample_4x3();
+
+    assert_eq!(matrix.transpose(), Matrix3x4::new(
+        Vector4::new(1.0, 0.0, 0.0, 4.0),
+        Vector4::new(0.0, 2.0, 0.0, 5.0),
+        Vector4::new(0.0, 0.0, 3.0, 6.0)
+    ));
+}
+
+#[test]
+fn matrix_2x3_add_matrix() {
+    let result = sample_2x3() + Matrix2x3::identity();
+
+    assert_eq!(result, Matrix2x3::new(Vector3::new(2.0, 2.0, 3.0), Vector3::new(4.0, 6.0, 6.0)));
+}
+
+#[test]
+fn matrix_2x3_add_assign_matrix() {
+    let mut matrix = sample_2x3();
+    matrix += Matrix2x3::identity();
+
+    assert_eq!(matrix, Matrix2x3::new(Vector3::new(2.0, 2.0, 3.0), Vector3::new(4.0, 6.0, 6.0)));
+}
+
+#[test]
+fn matrix_2x3_sub_matrix() {
+    let result = sample_2x3() - Matrix2x3::identity();
+
+    assert_eq!(result, Matrix2x3::new(Vector3::new(0.0, 2.0, 3.0), Vector3::new(4.0, 4.0, 6.0)));
+}
+
+#[test]
+fn matrix_2x3_sub_assign_matrix() {
+    let mut matrix = sample_2x3();
+    matrix -= Matrix2x3::identity();
+
+    assert_eq!(matrix, Matrix2x3::new(Vector3::new(0.0, 2.0, 3.0), Vector3::new(4.0, 4.0, 6.0)));
+}
+
+#[test]
+fn matrix_2x3_mul_scalar() {
+    let result = sample_2x3() * 2.0;
+
+    assert_eq!(result, Matrix2x3::new(Vector3::new(2.0, 4.0, 6.0), Vector3::new(8.0, 10.0, 12.0)));
+}
+
+#[test]
+fn matrix_2x3_mul_assign_scalar() {
+    let mut matrix = sample_2x3();
+    matrix *= 2.0;
+
+    assert_eq!(matrix, Matrix2x3::new(Vector3::new(2.0, 4.0, 6.0), Vector3::new(8.0, 10.0, 12.0)));
+}
+
+#[test]
+fn matrix_2x3_div_scalar() {
+    let result = sample_2x3() / 2.0;
+
+    assert_eq!(result, Matrix2x3::new(Vector3::new(0.5, 1.0, 1.5), Vector3::new(2.0, 2.5, 3.0)));
+}
+
+#[test]
+fn matrix_2x3_div_assign_scalar() {
+    let mut matrix = sample_2x3();
+    matrix /= 2.0;
+
+    assert_eq!(matrix, Matrix2x3::new(Vector3::new(0.5, 1.0, 1.5), Vector3::new(2.0, 2.5, 3.0)));
+}
+
+#[test]
+fn matrix_2x3_neg() {
+    let result = -sample_2x3();
+
+    assert_eq!(result, Matrix2x3::new(Vector3::new(-1.0, -2.0, -3.0), Vector3::new(-4.0, -5.0, -6.0)));
+}
+
+#[test]
+fn matrix_2x3_mul_vector() {
+    let result: Vector3 = sample_2x3() * Vector2::new(1.0, -1.0);
+
+    assert_eq!(result, Vector3::new(-3.0, -3.0, -3.0));
+}
+
+#[test]
+fn vector_3_mul_matrix_2x3() {
+    let result: Vector2 = Vector3::new(1.0, 0.0, -1.0) * sample_2x3();
+
+    assert_eq!(result, Vector2::new(-2.0, -2.0));
+}
+
+#[test]
+fn matrix_4x3_mul_vector() {
+    let result: Vector3 = sample_4x3() * Vector4::new(1.0, 1.0, 1.0, 1.0);
+
+    assert_eq!(result, Vector3::new(5.0, 7.0, 9.0));
+}
+
+#[test]
+fn matrix_4x3_mul_vector_skinning() {
+    let bind = Matrix4::from_translation(Vector3::new(0.0, 1.0, 0.0)).scale(Vector3::new(2.0, 2.0, 2.0));
+    let bone = Matrix4x3::new(Vector3::from(bind.x), Vector3::from(bind.y), Vector3::from(bind.z), Vector3::from(bind.w));
+    let position = Vector4::new(1.0, 2.0, 3.0, 1.0);
+    let normal = Vector4::new(0.0, 0.0, 1.0, 0.0);
+    let skinned: Vector3 = bone * position * 0.75 + bone * position * 0.25;
+
+    assert_eq!(skinned, Vector3::new(2.0, 5.0, 6.0));
+    assert_eq!(bone * normal, Vector3::new(0.0, 0.0, 2.0));
+}
+
+#[test]
+fn vector_4_mul_matrix_3x4() {
+    let result: Vector3 = Vector4::new(1.0, 1.0, 1.0, 1.0) * sample_4x3().transpose();
+
+    assert_eq!(result, Vector3::new(5.0, 7.0, 9.0));
+}
+
+#[test]
+fn matrix_2x3_mul_matrix_3x2() {
+    let result: Matrix3 = sample_2x3() * sample_3x2();
+
+    assert_eq!(result, Matrix3::new(
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(-2.0, -1.0, 0.0),
+        Vector3::new(12.0, 15.0, 18.0)
+    ));
+}
+
+#[test]
+fn matrix_3x2_mul_matrix_2x3() {
+    let result: Matrix2 = sample_3x2() * sample_2x3();
+
+    assert_eq!(result, Matrix2::new(Vector2::new(5.0, 7.0), Vector2::new(14.0, 13.0)));
+}
+
+#[test]
+fn matrix_3x4_mul_matrix_4x3() {
+    let result: Matrix4 = sample_4x3().transpose() * sample_4x3();
+
+    assert_eq!(result, Matrix4::new(
+        Vector4::new(1.0, 0.0, 0.0, 4.0),
+        Vector4::new(0.0, 4.0, 0.0, 10.0),
+        Vector4::new(0.0, 0.0, 9.0, 18.0),
+        Vector4::new(4.0, 10.0, 18.0, 77.0)
+    ));
+}
+
+#[test]
+fn matrix_4x3_mul_matrix_4() {
+    let result: Matrix4x3 = sample_4x3() * Matrix4::identity();
+
+    assert_eq!(result, sample_4x3());
+}
+
+#[test]
+fn matrix_3_mul_matrix_4x3() {
+    let result: Matrix4x3 = Matrix3::from_diagonal(Vector3::new(2.0, 1.0, 0.5)) * sample_4x3();
+
+    assert_eq!(result, Matrix4x3::new(
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(0.0, 2.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.5),
+        Vector3::new(8.0, 5.0, 3.0)
+    ));
+}
+
+#[test]
+fn matrix_2x4_mul_matrix_4x2() {
+    let first = Matrix2x4::new(Vector4::new(1.0, 2.0, 3.0, 4.0), Vector4::new(0.0, 1.0, 0.0, 1.0));
+    let second = Matrix4x2::new(Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 0.0));
+    let result: Matrix4 = first * second;
+
+    assert_eq!(result, Matrix4::new(
+        Vector4::new(1.0, 2.0, 3.0, 4.0),
+        Vector4::new(0.0, 1.0, 0.0, 1.0),
+        Vector4::new(1.0, 3.0, 3.0, 5.0),
+        Vector4::new(2.0, 4.0, 6.0, 8.0)
+    ));
+}
+
+#[test]
+fn matrix_2x3_debug_struct() {
+    let matrix = Matrix2x3::identity();
+
+    assert_eq!(format!("{:?}", matrix), "TMatrix2x3<f32> { \
+        x: TVector3<f32> { x: 1.0, y: 0.0, z: 0.0 }, \
+        y: TVector3<f32> { x: 0.0, y: 1.0, z: 0.0 } }");
+}
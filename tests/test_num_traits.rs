@@ -0,0 +1,85 @@
+use iomath::types::basic_types::num_traits::*;
+use iomath::types::basic_types::{ TVector2, TVector3 };
+use iomath::extended::vectors::U8Vector4;
+
+fn hypotenuse<T>(a: T, b: T) -> T where T : Float {
+    TVector2::new(a, b).length()
+}
+
+fn sum_of_range<T>(count: usize) -> T where T : Integer {
+    (0..count).fold(T::zero(), |sum, index| sum + T::cast(index))
+}
+
+#[test]
+fn num_traits_zero_one() {
+    assert_eq!(u8::zero(), 0);
+    assert_eq!(usize::one(), 1);
+    assert_eq!(isize::zero(), 0);
+    assert_eq!(f64::one(), 1.0);
+    assert_eq!(U8Vector4::empty(), U8Vector4::new(0, 0, 0, 0));
+    assert_eq!(TVector3::<isize>::from_scalar(1), TVector3::new(1, 1, 1));
+}
+
+#[test]
+fn num_traits_signed() {
+    assert_eq!(Signed::abs(-3_i8), 3);
+    assert_eq!(Signed::abs(-2.5_f32), 2.5);
+    assert_eq!(Signed::signum(0_isize), 0);
+    assert_eq!(Signed::signum(-0.0_f64), -1.0);
+    assert!(Signed::is_negative(-1_i64));
+    assert!(!Signed::is_negative(-0.0_f32));
+}
+
+#[test]
+fn num_traits_checked_recip() {
+    assert_eq!(4.0_f32.checked_recip(), Some(0.25));
+    assert_eq!(0.0_f64.checked_recip(), None);
+    assert_eq!((-1_i32).checked_recip(), Some(-1));
+    assert_eq!(1_u8.checked_recip(), Some(1));
+    assert_eq!(2_i64.checked_recip(), None);
+    assert_eq!(0_usize.checked_recip(), None);
+}
+
+#[test]
+fn num_traits_bounded() {
+    assert_eq!(<u8 as Bounded>::min_value(), 0);
+    assert_eq!(<u8 as Bounded>::max_value(), 255);
+    assert_eq!(<isize as Bounded>::min_value(), isize::MIN);
+    assert_eq!(<f32 as Bounded>::max_value(), f32::MAX);
+}
+
+#[test]
+fn num_traits_cast() {
+    assert_eq!(<u8 as Cast<f32>>::cast(300.7), 255);
+    assert_eq!(<i32 as Cast<f64>>::cast(-2.9), -2);
+    assert_eq!(<f32 as Cast<usize>>::cast(7), 7.0);
+    assert_eq!(<u16 as Cast<i8>>::cast(-1), 65535);
+}
+
+#[test]
+fn num_traits_integer() {
+    assert_eq!(Integer::div_euclid(-7_i32, 2), -4);
+    assert_eq!(Integer::rem_euclid(-7_i32, 2), 1);
+    assert_eq!(Integer::pow(3_u8, 4), 81);
+    assert_eq!(sum_of_range::<u64>(5), 10);
+    assert_eq!(sum_of_range::<i16>(4), 6);
+}
+
+#[test]
+fn num_traits_float() {
+    assert_eq!(<f32 as Float>::PI, std::f32::consts::PI);
+    assert_eq!(<f64 as Float>::EPSILON, f64::EPSILON);
+    assert_eq!(Float::sqrt(16.0_f32), 4.0);
+    assert_eq!(Float::atan2(0.0_f64, 1.0), 0.0);
+    assert!(Float::is_nan(<f32 as Float>::NAN));
+    assert_eq!(hypotenuse(3.0_f32, 4.0), 5.0);
+    assert_eq!(hypotenuse(5.0_f64, 12.0), 13.0);
+}
+
+#[test]
+fn num_traits_float_as_usize() {
+    assert_eq!(Float::as_usize(2.9_f32), 2);
+    assert_eq!(Float::as_usize(-1.0_f64), 0);
+    assert_eq!(Float::as_usize(<f64 as Float>::NAN), 0);
+    assert_eq!(Float::as_usize(<f32 as Float>::INFINITY), usize::MAX);
+}
@@ -0,0 +1,728 @@
+use iomath::quaternions::{ Quaternion, EulerOrder, ParseError };
+use iomath::types::basic_types::TQuaternion;
+use iomath::extended::quaternions::F64Quaternion;
+use iomath::extended::vectors::F64Vector3;
+use iomath::extended::matrices::F64Matrix3;
+use iomath::vectors::{ Vector2, Vector3, Vector4 };
+
+use std::collections::HashSet;
+use std::f32::consts::{ PI, FRAC_PI_2, FRAC_PI_4 };
+
+const ORDERS: [EulerOrder; 12] = [
+    EulerOrder::XYZ, EulerOrder::XZY, EulerOrder::YXZ, EulerOrder::YZX, EulerOrder::ZXY, EulerOrder::ZYX,
+    EulerOrder::XYX, EulerOrder::XZX, EulerOrder::YXY, EulerOrder::YZY, EulerOrder::ZXZ, EulerOrder::ZYZ
+];
+
+fn approx_eq(first: f64, second: f64) -> bool {
+    (first - second).abs() < 1e-9
+}
+
+fn same_rotation(first: F64Quaternion, second: F64Quaternion) -> bool {
+    approx_eq(first.dot(second).abs(), 1.0)
+}
+
+fn rotate(quaternion: Quaternion, vector: Vector3) -> Vector3 {
+    Vector3::from(quaternion * Quaternion::new(0.0, vector.x, vector.y, vector.z) * quaternion.conjugate())
+}
+
+fn assert_vector_3_approx_eq(first: Vector3, second: Vector3) {
+    assert!(first.distance(second) < 1e-6, "{:?} != {:?}", first, second);
+}
+
+#[test]
+fn quaternion_identity() {
+    let quaternion = Quaternion::identity();
+
+    assert_eq!(quaternion, Quaternion { w: 1.0, x: 0.0, y: 0.0, z: 0.0 });
+}
+
+#[test]
+fn quaternion_new() {
+    let quaternion = Quaternion::new(0.5, -1.5, 2.0, 3.0);
+
+    assert_eq!(quaternion, Quaternion { w: 0.5, x: -1.5, y: 2.0, z: 3.0 });
+}
+
+#[test]
+fn quaternion_copy() {
+    let quaternion_from = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    let quaternion_to = quaternion_from;
+
+    assert_eq!(quaternion_from, Quaternion::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(quaternion_to, Quaternion::new(1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn quaternion_dot() {
+    let quaternion = Quaternion::new(1.0, -2.0, 0.5, 3.0);
+
+    assert_eq!(quaternion.dot(Quaternion::new(2.0, 1.0, 4.0, -1.0)), -1.0);
+}
+
+#[test]
+fn quaternion_norm_squared() {
+    let quaternion = Quaternion::new(1.0, -2.0, 2.0, 4.0);
+
+    assert_eq!(quaternion.norm_squared(), 25.0);
+}
+
+#[test]
+fn quaternion_norm() {
+    let quaternion = Quaternion::new(1.0, -2.0, 2.0, 4.0);
+
+    assert_eq!(quaternion.norm(), 5.0);
+}
+
+#[test]
+fn quaternion_normalize() {
+    let quaternion = Quaternion::new(0.0, 3.0, 0.0, -4.0);
+
+    assert_eq!(quaternion.normalize(), Quaternion::new(0.0, 0.6, 0.0, -0.8));
+}
+
+#[test]
+fn quaternion_conjugate() {
+    let quaternion = Quaternion::new(1.0, -2.0, 3.0, -4.0);
+
+    assert_eq!(quaternion.conjugate(), Quaternion::new(1.0, 2.0, -3.0, 4.0));
+}
+
+#[test]
+fn quaternion_inverse() {
+    let quaternion = Quaternion::new(2.0, 0.0, -2.0, 0.0);
+    let inverse = quaternion.inverse();
+
+    assert_eq!(inverse, Quaternion::new(0.25, 0.0, 0.25, 0.0));
+    assert_eq!(quaternion * inverse, Quaternion::identity());
+    assert_eq!(inverse * quaternion, Quaternion::identity());
+}
+
+#[test]
+fn quaternion_add_quaternion() {
+    let quaternion = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    let result = quaternion + Quaternion::new(0.5, -1.0, 1.0, -4.0);
+
+    assert_eq!(result, Quaternion::new(1.5, 1.0, 4.0, 0.0));
+}
+
+#[test]
+fn quaternion_add_assign_quaternion() {
+    let mut quaternion = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    quaternion += Quaternion::new(0.5, -1.0, 1.0, -4.0);
+
+    assert_eq!(quaternion, Quaternion::new(1.5, 1.0, 4.0, 0.0));
+}
+
+#[test]
+fn quaternion_sub_quaternion() {
+    let quaternion = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    let result = quaternion - Quaternion::new(0.5, -1.0, 1.0, -4.0);
+
+    assert_eq!(result, Quaternion::new(0.5, 3.0, 2.0, 8.0));
+}
+
+#[test]
+fn quaternion_sub_assign_quaternion() {
+    let mut quaternion = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    quaternion -= Quaternion::new(0.5, -1.0, 1.0, -4.0);
+
+    assert_eq!(quaternion, Quaternion::new(0.5, 3.0, 2.0, 8.0));
+}
+
+#[test]
+fn quaternion_mul_scalar() {
+    let quaternion = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(quaternion * 0.5, Quaternion::new(0.5, 1.0, 1.5, 2.0));
+}
+
+#[test]
+fn quaternion_mul_assign_scalar() {
+    let mut quaternion = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    quaternion *= 0.5;
+
+    assert_eq!(quaternion, Quaternion::new(0.5, 1.0, 1.5, 2.0));
+}
+
+#[test]
+fn quaternion_mul_quaternion_basis() {
+    let i = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+    let j = Quaternion::new(0.0, 0.0, 1.0, 0.0);
+    let k = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+
+    assert_eq!(i * j, k);
+    assert_eq!(j * k, i);
+    assert_eq!(k * i, j);
+    assert_eq!(j * i, -k);
+    assert_eq!(i * i, -Quaternion::identity());
+}
+
+#[test]
+fn quaternion_mul_quaternion() {
+    let quaternion = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    let result = quaternion * Quaternion::new(5.0, 6.0, 7.0, 8.0);
+
+    assert_eq!(result, Quaternion::new(-60.0, 12.0, 30.0, 24.0));
+}
+
+#[test]
+fn quaternion_mul_assign_quaternion() {
+    let mut quaternion = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    quaternion *= Quaternion::new(5.0, 6.0, 7.0, 8.0);
+
+    assert_eq!(quaternion, Quaternion::new(-60.0, 12.0, 30.0, 24.0));
+}
+
+#[test]
+fn quaternion_div_scalar() {
+    let quaternion = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(quaternion / 2.0, Quaternion::new(0.5, 1.0, 1.5, 2.0));
+}
+
+#[test]
+fn quaternion_div_assign_scalar() {
+    let mut quaternion = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    quaternion /= 2.0;
+
+    assert_eq!(quaternion, Quaternion::new(0.5, 1.0, 1.5, 2.0));
+}
+
+#[test]
+fn quaternion_neg() {
+    let quaternion = Quaternion::new(1.0, -2.0, 3.0, -4.0);
+
+    assert_eq!(-quaternion, Quaternion::new(-1.0, 2.0, -3.0, 4.0));
+}
+
+#[test]
+fn quaternion_debug_struct() {
+    let quaternion = Quaternion::identity();
+
+    assert_eq!(format!("{:?}", quaternion), "TQuaternion<f32> { w: 1.0, x: 0.0, y: 0.0, z: 0.0 }");
+}
+
+
+#[test]
+fn quaternion_from_axis_angle() {
+    let quaternion = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+
+    assert_eq!(quaternion, Quaternion::new(FRAC_PI_4.cos(), 0.0, 0.0, FRAC_PI_4.sin()));
+    assert_vector_3_approx_eq(rotate(quaternion, Vector3::new(1.0, 0.0, 0.0)), Vector3::new(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn quaternion_from_rotation_x() {
+    let quaternion = Quaternion::from_rotation_x(0.7);
+
+    assert_eq!(quaternion, Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 0.7));
+    assert_vector_3_approx_eq(rotate(Quaternion::from_rotation_x(FRAC_PI_2), Vector3::new(0.0, 1.0, 0.0)), Vector3::new(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn quaternion_from_rotation_y() {
+    let quaternion = Quaternion::from_rotation_y(-1.2);
+
+    assert_eq!(quaternion, Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), -1.2));
+    assert_vector_3_approx_eq(rotate(Quaternion::from_rotation_y(FRAC_PI_2), Vector3::new(0.0, 0.0, 1.0)), Vector3::new(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn quaternion_from_rotation_z() {
+    let quaternion = Quaternion::from_rotation_z(2.5);
+
+    assert_eq!(quaternion, Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 2.5));
+    assert_vector_3_approx_eq(rotate(Quaternion::from_rotation_z(FRAC_PI_2), Vector3::new(1.0, 0.0, 0.0)), Vector3::new(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn quaternion_from_euler_is_intrinsic() {
+    let quaternion = Quaternion::from_euler(EulerOrder::ZXY, 0.3, -0.8, 1.9);
+
+    assert_eq!(quaternion, Quaternion::from_rotation_z(0.3) * Quaternion::from_rotation_x(-0.8) * Quaternion::from_rotation_y(1.9));
+}
+
+#[test]
+fn quaternion_from_euler_proper() {
+    let quaternion = Quaternion::from_euler(EulerOrder::ZXZ, 0.3, 1.1, -2.0);
+
+    assert_eq!(quaternion, Quaternion::from_rotation_z(0.3) * Quaternion::from_rotation_x(1.1) * Quaternion::from_rotation_z(-2.0));
+}
+
+#[test]
+fn quaternion_to_euler_tait_bryan() {
+    let angles = [(0.3, -0.8, 1.9), (-2.9, 1.4, 0.1), (3.0, -1.5, -3.0), (0.0, 0.2, 0.0)];
+
+    for &order in ORDERS[..6].iter() {
+        for &(first, second, third) in angles.iter() {
+            let quaternion = F64Quaternion::from_euler(order, first, second, third);
+            let (a, b, c) = quaternion.to_euler(order);
+
+            assert!(approx_eq(a, first) && approx_eq(b, second) && approx_eq(c, third), "{:?}: {:?} != {:?}", order, (a, b, c), (first, second, third));
+        }
+    }
+}
+
+#[test]
+fn quaternion_to_euler_proper() {
+    let angles = [(0.3, 0.8, 1.9), (-2.9, 3.0, 0.1), (3.0, 1.5, -3.0), (-0.4, 0.2, 0.6)];
+
+    for &order in ORDERS[6..].iter() {
+        for &(first, second, third) in angles.iter() {
+            let quaternion = F64Quaternion::from_euler(order, first, second, third);
+            let (a, b, c) = quaternion.to_euler(order);
+
+            assert!(approx_eq(a, first) && approx_eq(b, second) && approx_eq(c, third), "{:?}: {:?} != {:?}", order, (a, b, c), (first, second, third));
+        }
+    }
+}
+
+#[test]
+fn quaternion_to_euler_gimbal_lock() {
+    use std::f64::consts::{ PI, FRAC_PI_2 };
+
+    for (index, &order) in ORDERS.iter().enumerate() {
+        let locks = if index < 6 { [FRAC_PI_2, -FRAC_PI_2] } else { [0.0, PI] };
+        for &second in locks.iter() {
+            let quaternion = F64Quaternion::from_euler(order, 0.4, second, -1.1);
+            let (a, b, c) = quaternion.to_euler(order);
+
+            assert!(approx_eq(b, second) && approx_eq(c, 0.0), "{:?}: {:?}", order, (a, b, c));
+            assert!(same_rotation(F64Quaternion::from_euler(order, a, b, c), quaternion), "{:?}: {:?}", order, (a, b, c));
+        }
+    }
+}
+
+#[test]
+fn quaternion_from_rotation_arc() {
+    let from = Vector3::new(1.0, 0.0, 0.0);
+    let to = Vector3::new(0.0, 0.6, 0.8);
+    let quaternion = Quaternion::from_rotation_arc(from, to);
+
+    assert!((quaternion.norm() - 1.0).abs() < 1e-6);
+    assert_vector_3_approx_eq(rotate(quaternion, from), to);
+    assert_vector_3_approx_eq(rotate(quaternion, from.cross(to)), from.cross(to));
+}
+
+#[test]
+fn quaternion_from_rotation_arc_parallel() {
+    let from = Vector3::new(0.0, 0.6, 0.8);
+
+    assert_eq!(Quaternion::from_rotation_arc(from, from), Quaternion::identity());
+}
+
+#[test]
+fn quaternion_from_rotation_arc_antiparallel() {
+    for &from in [Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.6, -0.8), Vector3::new(0.0, 0.0, 1.0)].iter() {
+        let quaternion = Quaternion::from_rotation_arc(from, -from);
+
+        assert!((quaternion.norm() - 1.0).abs() < 1e-6);
+        assert_vector_3_approx_eq(rotate(quaternion, from), -from);
+    }
+}
+
+#[test]
+fn quaternion_to_axis_angle() {
+    let axis = F64Vector3::new(2.0, -3.0, 6.0) / 7.0;
+    let (result_axis, result_angle) = F64Quaternion::from_axis_angle(axis, 2.2).to_axis_angle();
+
+    assert!(result_axis.distance(axis) < 1e-9);
+    assert!(approx_eq(result_angle, 2.2));
+}
+
+#[test]
+fn quaternion_to_axis_angle_identity() {
+    let (axis, angle) = Quaternion::identity().to_axis_angle();
+
+    assert_eq!(axis, Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(angle, 0.0);
+}
+
+#[test]
+fn quaternion_to_axis_angle_half_turn() {
+    let (axis, angle) = Quaternion::from_rotation_y(PI).to_axis_angle();
+
+    assert_vector_3_approx_eq(axis, Vector3::new(0.0, 1.0, 0.0));
+    assert!((angle - PI).abs() < 1e-6);
+}
+
+#[test]
+fn quaternion_rotate_vector() {
+    let quaternion = Quaternion::from_axis_angle(Vector3::new(2.0, -3.0, 6.0) / 7.0, 1.3);
+    let vector = Vector3::new(0.5, 4.0, -2.0);
+
+    assert_vector_3_approx_eq(quaternion.rotate_vector(vector), rotate(quaternion, vector));
+    assert_vector_3_approx_eq(Quaternion::from_rotation_z(FRAC_PI_2).rotate_vector(Vector3::new(1.0, 0.0, 0.0)), Vector3::new(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn quaternion_rotate_vector_identity() {
+    let vector = Vector3::new(0.5, 4.0, -2.0);
+
+    assert_eq!(Quaternion::identity().rotate_vector(vector), vector);
+}
+
+#[test]
+fn quaternion_mul_vector() {
+    let quaternion = Quaternion::from_euler(EulerOrder::YXZ, 0.3, -1.2, 2.0);
+    let vector = Vector3::new(-1.0, 2.0, 0.25);
+
+    assert_eq!(quaternion * vector, quaternion.rotate_vector(vector));
+    assert_vector_3_approx_eq(quaternion * vector, rotate(quaternion, vector));
+}
+
+#[test]
+fn quaternion_rotate_slice() {
+    let quaternion = Quaternion::from_rotation_arc(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.6, 0.8));
+    let source = [Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0), Vector3::new(-3.0, 0.5, 2.0)];
+    let mut vectors = source;
+    quaternion.rotate_slice(&mut vectors);
+
+    for (rotated, vector) in vectors.iter().zip(source.iter()) {
+        assert_eq!(*rotated, quaternion * *vector);
+    }
+    assert_vector_3_approx_eq(vectors[0], Vector3::new(0.0, 0.6, 0.8));
+}
+
+fn assert_quaternion_approx_eq(first: F64Quaternion, second: F64Quaternion) {
+    assert!((first - second).norm() < 1e-9, "{:?} != {:?}", first, second);
+}
+
+#[test]
+fn quaternion_lerp() {
+    let quaternion = Quaternion::new(1.0, 2.0, -4.0, 0.0);
+    let result = quaternion.lerp(Quaternion::new(3.0, 0.0, 4.0, 1.0), 0.25);
+
+    assert_eq!(result, Quaternion::new(1.5, 1.5, -2.0, 0.25));
+}
+
+#[test]
+fn quaternion_nlerp() {
+    let result = F64Quaternion::identity().nlerp(F64Quaternion::from_rotation_z(std::f64::consts::FRAC_PI_2), 0.5);
+
+    assert_quaternion_approx_eq(result, F64Quaternion::new(0.9238795325112867, 0.0, 0.0, 0.3826834323650898));
+}
+
+#[test]
+fn quaternion_nlerp_shortest_path() {
+    let first = F64Quaternion::from_rotation_x(0.2);
+    let second = F64Quaternion::from_rotation_x(1.0);
+
+    assert_quaternion_approx_eq(first.nlerp(-second, 0.3), first.nlerp(second, 0.3));
+}
+
+#[test]
+fn quaternion_slerp() {
+    let first = F64Quaternion::identity();
+    let second = F64Quaternion::from_rotation_z(std::f64::consts::FRAC_PI_2);
+
+    assert_quaternion_approx_eq(first.slerp(second, 0.5), F64Quaternion::new(0.9238795325112867, 0.0, 0.0, 0.3826834323650898));
+    assert_quaternion_approx_eq(first.slerp(second, 1.0 / 3.0), F64Quaternion::new(0.9659258262890683, 0.0, 0.0, 0.25881904510252074));
+    assert_quaternion_approx_eq(first.slerp(second, 0.0), first);
+    assert_quaternion_approx_eq(first.slerp(second, 1.0), second);
+}
+
+#[test]
+fn quaternion_slerp_reference() {
+    let first = F64Quaternion::from_euler(EulerOrder::XYZ, 0.3, -0.2, 1.1);
+    let second = F64Quaternion::from_euler(EulerOrder::XYZ, -1.4, 0.9, 0.5);
+    let result = first.slerp(second, 0.7);
+
+    assert_quaternion_approx_eq(result, F64Quaternion::new(0.8811312577968844, -0.3467963733205007, 0.30454006539468904, 0.10293362207960458));
+}
+
+#[test]
+fn quaternion_slerp_shortest_path() {
+    let first = F64Quaternion::from_rotation_y(0.4);
+    let second = F64Quaternion::from_rotation_y(2.8);
+
+    assert_quaternion_approx_eq(first.slerp(-second, 0.6), first.slerp(second, 0.6));
+    assert_quaternion_approx_eq(first.slerp(second, 0.5), F64Quaternion::from_rotation_y(1.6));
+}
+
+#[test]
+fn quaternion_slerp_small_angle() {
+    let first = F64Quaternion::from_rotation_x(1.0);
+    let second = F64Quaternion::from_rotation_x(1.0 + 1e-9);
+    let result = first.slerp(second, 0.5);
+
+    assert!((result.norm() - 1.0).abs() < 1e-12);
+    assert_quaternion_approx_eq(result, F64Quaternion::from_rotation_x(1.0 + 0.5e-9));
+}
+
+#[test]
+fn quaternion_squad_endpoints() {
+    let first = F64Quaternion::from_rotation_x(0.3);
+    let second = F64Quaternion::from_euler(EulerOrder::ZYX, 0.2, 0.4, 0.6);
+    let first_control = first.squad_control_point(F64Quaternion::identity(), second);
+    let second_control = second.squad_control_point(first, F64Quaternion::from_rotation_z(1.0));
+
+    assert_quaternion_approx_eq(first.squad(second, first_control, second_control, 0.0), first);
+    assert_quaternion_approx_eq(first.squad(second, first_control, second_control, 1.0), second);
+}
+
+#[test]
+fn quaternion_squad_control_point_uniform() {
+    let current = F64Quaternion::from_rotation_z(0.5);
+    let control = current.squad_control_point(F64Quaternion::from_rotation_z(0.0), F64Quaternion::from_rotation_z(1.0));
+
+    assert_quaternion_approx_eq(control, current);
+}
+
+#[test]
+fn quaternion_squad_spline_uniform() {
+    let keyframes: Vec<F64Quaternion> = (0..4).map(|index| F64Quaternion::from_rotation_z(0.5 * index as f64)).collect();
+
+    assert_quaternion_approx_eq(F64Quaternion::squad_spline(&keyframes, 1.25), F64Quaternion::from_rotation_z(0.625));
+    assert_quaternion_approx_eq(F64Quaternion::squad_spline(&keyframes, 0.0), keyframes[0]);
+    assert_quaternion_approx_eq(F64Quaternion::squad_spline(&keyframes, 3.0), keyframes[3]);
+}
+
+#[test]
+fn quaternion_squad_spline_keyframes() {
+    let keyframes = [
+        F64Quaternion::identity(),
+        F64Quaternion::from_rotation_x(1.0),
+        -F64Quaternion::from_euler(EulerOrder::XYZ, 1.0, 0.8, 0.0),
+        F64Quaternion::from_euler(EulerOrder::XYZ, 0.2, 0.8, 1.2)
+    ];
+
+    for (index, keyframe) in keyframes.iter().enumerate() {
+        assert!(same_rotation(F64Quaternion::squad_spline(&keyframes, index as f64), *keyframe));
+    }
+
+    let result = F64Quaternion::squad_spline(&keyframes, 1.5);
+    assert!((result.norm() - 1.0).abs() < 1e-9);
+    assert_quaternion_approx_eq(result, F64Quaternion::new(0.8422711357158373, 0.5042825586599678, 0.17721223294826966, 0.06981589693470982));
+}
+
+#[test]
+fn quaternion_squad_spline_clamped() {
+    let keyframes = [F64Quaternion::identity(), F64Quaternion::from_rotation_z(1.0), F64Quaternion::from_rotation_z(2.0)];
+    let single = [F64Quaternion::from_rotation_x(0.5)];
+
+    assert_quaternion_approx_eq(F64Quaternion::squad_spline(&keyframes, -1.0), keyframes[0]);
+    assert_quaternion_approx_eq(F64Quaternion::squad_spline(&keyframes, 7.5), keyframes[2]);
+    assert_quaternion_approx_eq(F64Quaternion::squad_spline(&single, 0.75), single[0]);
+}
+
+#[test]
+fn quaternion_from_rotation_matrix() {
+    let axes = [
+        F64Vector3::new(1.0, 0.0, 0.0), F64Vector3::new(0.0, 1.0, 0.0), F64Vector3::new(0.0, 0.0, 1.0),
+        F64Vector3::new(1.0, 2.0, -3.0).normalize(), F64Vector3::new(-0.3, 0.1, 0.9).normalize()
+    ];
+    let angles = [0.0, 0.5, 2.0, std::f64::consts::PI - 1e-7, std::f64::consts::PI];
+
+    for &axis in axes.iter() {
+        for &angle in angles.iter() {
+            let quaternion = F64Quaternion::from_axis_angle(axis, angle);
+            let result = F64Quaternion::from_rotation_matrix(F64Matrix3::from(quaternion));
+
+            assert!(approx_eq(result.norm(), 1.0));
+            assert!(same_rotation(result, quaternion), "{:?} != {:?}", result, quaternion);
+        }
+    }
+}
+
+#[test]
+fn quaternion_from_rotation_matrix_with_drift() {
+    let quaternion = F64Quaternion::from_axis_angle(F64Vector3::new(2.0, -1.0, 0.5).normalize(), 1.3);
+    let mut matrix = F64Matrix3::from(quaternion) * 1.01;
+    matrix.y += matrix.x * 1e-4;
+
+    let result = F64Quaternion::from_rotation_matrix(matrix);
+
+    assert!(approx_eq(result.norm(), 1.0));
+    assert!((result.dot(quaternion).abs() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn quaternion_exp() {
+    let quaternion = F64Quaternion::new(0.0, 0.0, 0.0, std::f64::consts::FRAC_PI_4).exp();
+
+    assert!(same_rotation(quaternion, F64Quaternion::from_rotation_z(std::f64::consts::FRAC_PI_2)));
+    assert!(approx_eq(quaternion.norm(), 1.0));
+    assert_eq!(F64Quaternion::new(0.0, 0.0, 0.0, 0.0).exp(), F64Quaternion::identity());
+    assert!(approx_eq(F64Quaternion::new(1.0, 0.0, 0.0, 0.0).exp().w, std::f64::consts::E));
+}
+
+#[test]
+fn quaternion_ln() {
+    let quaternion = F64Quaternion::from_axis_angle(F64Vector3::new(0.0, 1.0, 0.0), 1.2).ln();
+
+    assert!(approx_eq(quaternion.w, 0.0));
+    assert!(approx_eq(quaternion.x, 0.0));
+    assert!(approx_eq(quaternion.y, 0.6));
+    assert!(approx_eq(quaternion.z, 0.0));
+    assert_eq!(F64Quaternion::new(-1.0, 0.0, 0.0, 0.0).ln(), F64Quaternion::new(0.0, std::f64::consts::PI, 0.0, 0.0));
+}
+
+#[test]
+fn quaternion_exp_ln_roundtrip() {
+    let quaternion = F64Quaternion::new(0.5, -1.5, 2.0, 0.25);
+    let result = quaternion.ln().exp();
+
+    assert!(approx_eq(result.w, quaternion.w));
+    assert!(approx_eq(result.x, quaternion.x));
+    assert!(approx_eq(result.y, quaternion.y));
+    assert!(approx_eq(result.z, quaternion.z));
+}
+
+#[test]
+fn quaternion_powf() {
+    let axis = F64Vector3::new(1.0, -2.0, 2.0).normalize();
+    let quaternion = F64Quaternion::from_axis_angle(axis, 1.5);
+
+    assert!(same_rotation(quaternion.powf(0.5), F64Quaternion::from_axis_angle(axis, 0.75)));
+    assert!(same_rotation(quaternion.powf(2.0), quaternion * quaternion));
+    assert!(same_rotation(quaternion.powf(0.0), F64Quaternion::identity()));
+    assert!(approx_eq(F64Quaternion::new(2.0, 0.0, 0.0, 0.0).powf(3.0).w, 8.0));
+}
+
+#[test]
+fn quaternion_from_scaled_axis() {
+    let quaternion = F64Quaternion::from_scaled_axis(F64Vector3::new(0.0, 0.0, -2.0));
+
+    assert!(same_rotation(quaternion, F64Quaternion::from_rotation_z(-2.0)));
+    assert_eq!(F64Quaternion::from_scaled_axis(F64Vector3::new(0.0, 0.0, 0.0)), F64Quaternion::identity());
+}
+
+#[test]
+fn quaternion_to_scaled_axis() {
+    let scaled_axis = F64Vector3::new(0.3, -0.4, 1.2);
+
+    assert!(F64Quaternion::from_scaled_axis(scaled_axis).to_scaled_axis().distance(scaled_axis) < 1e-9);
+    assert!((-F64Quaternion::from_scaled_axis(scaled_axis)).to_scaled_axis().distance(scaled_axis) < 1e-9);
+    assert_eq!(F64Quaternion::identity().to_scaled_axis(), F64Vector3::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn quaternion_exp_integrates_angular_velocity() {
+    let angular_velocity = F64Vector3::new(0.0, 2.0, 0.0);
+    let mut orientation = F64Quaternion::identity();
+    for _ in 0..100 {
+        let half_step = angular_velocity * 0.005;
+        orientation = F64Quaternion::new(0.0, half_step.x, half_step.y, half_step.z).exp() * orientation;
+    }
+
+    assert!(same_rotation(orientation, F64Quaternion::from_rotation_y(2.0)));
+}
+
+#[test]
+fn quaternion_swizzle() {
+    let quaternion = Quaternion::new(4.0, 1.0, 2.0, 3.0);
+
+    assert_eq!(quaternion.xyz(), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(quaternion.wx(), Vector2::new(4.0, 1.0));
+    assert_eq!(quaternion.wxyz(), Vector4::new(4.0, 1.0, 2.0, 3.0));
+    assert_eq!(quaternion.rgba(), Vector4::new(1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn quaternion_set_swizzle() {
+    let mut quaternion = Quaternion::identity();
+    quaternion.set_xyz(Vector3::new(1.0, 2.0, 3.0));
+
+    assert_eq!(quaternion, Quaternion::new(1.0, 1.0, 2.0, 3.0));
+
+    quaternion.set_wx(Vector2::new(0.5, -1.0));
+
+    assert_eq!(quaternion, Quaternion::new(0.5, -1.0, 2.0, 3.0));
+}
+
+#[test]
+fn quaternion_from_array() {
+    let quaternion = Quaternion::from([0.5, -1.0, 2.0, 3.5]);
+
+    assert_eq!(quaternion, Quaternion::new(0.5, -1.0, 2.0, 3.5));
+}
+
+#[test]
+fn quaternion_into_array() {
+    let array: [f32; 4] = Quaternion::new(0.5, -1.0, 2.0, 3.5).into();
+
+    assert_eq!(array, [0.5, -1.0, 2.0, 3.5]);
+}
+
+#[test]
+fn quaternion_from_tuple() {
+    let quaternion = Quaternion::from((0.5, -1.0, 2.0, 3.5));
+
+    assert_eq!(quaternion, Quaternion::new(0.5, -1.0, 2.0, 3.5));
+}
+
+#[test]
+fn quaternion_into_tuple() {
+    let tuple: (f32, f32, f32, f32) = Quaternion::new(0.5, -1.0, 2.0, 3.5).into();
+
+    assert_eq!(tuple, (0.5, -1.0, 2.0, 3.5));
+}
+
+#[test]
+fn quaternion_as_array() {
+    let quaternion = Quaternion::new(0.5, -1.0, 2.0, 3.5);
+
+    assert_eq!(quaternion.as_array(), &[0.5, -1.0, 2.0, 3.5]);
+    assert_eq!(std::mem::size_of::<Quaternion>(), std::mem::size_of::<[f32; 4]>());
+}
+
+#[test]
+fn quaternion_as_mut_array() {
+    let mut quaternion = Quaternion::identity();
+    quaternion.as_mut_array()[3] = 2.0;
+
+    assert_eq!(quaternion, Quaternion::new(1.0, 0.0, 0.0, 2.0));
+}
+
+#[test]
+fn quaternion_from_slice() {
+    let quaternion = Quaternion::from_slice(&[0.5, -1.0, 2.0, 3.5, 9.0]);
+
+    assert_eq!(quaternion, Quaternion::new(0.5, -1.0, 2.0, 3.5));
+}
+
+#[test]
+#[should_panic]
+fn quaternion_from_slice_too_short() {
+    let _ = Quaternion::from_slice(&[1.0; 3]);
+}
+
+#[test]
+fn quaternion_write_to_slice() {
+    let mut buffer = [0.0; 4];
+    Quaternion::new(0.5, -1.0, 2.0, 3.5).write_to_slice(&mut buffer);
+
+    assert_eq!(buffer, [0.5, -1.0, 2.0, 3.5]);
+}
+
+#[test]
+fn quaternion_default() {
+    assert_eq!(Quaternion::default(), Quaternion::identity());
+}
+
+#[test]
+fn quaternion_hash() {
+    let mut set = HashSet::new();
+    set.insert(TQuaternion::new(1, 0, 0, 0));
+
+    assert!(set.contains(&TQuaternion::new(1, 0, 0, 0)));
+    assert!(!set.contains(&TQuaternion::new(0, 1, 0, 0)));
+}
+
+#[test]
+fn quaternion_display() {
+    let quaternion = Quaternion::new(0.5, -1.0, 2.0, 3.25);
+
+    assert_eq!(format!("{}", quaternion), "(0.5, -1, 2, 3.25)");
+    assert_eq!(format!("{:.1}", quaternion), "(0.5, -1.0, 2.0, 3.2)");
+}
+
+#[test]
+fn quaternion_from_str() {
+    assert_eq!("(0.5, -1, 2, 3.25)".parse(), Ok(Quaternion::new(0.5, -1.0, 2.0, 3.25)));
+    assert_eq!("0.5 -1 2 3.25".parse(), Ok(Quaternion::new(0.5, -1.0, 2.0, 3.25)));
+    assert_eq!("0.5 -1 2".parse::<Quaternion>(), Err(ParseError::WrongComponentCount { expected: 4, found: 3 }));
+    assert_eq!("0.5, -1, 2)".parse::<Quaternion>(), Err(ParseError::UnbalancedParentheses));
+    assert_eq!("(0.5, -1, 2, w)".parse::<Quaternion>(), Err(ParseError::InvalidComponent(3)));
+}
@@ -0,0 +1,113 @@
+use iomath::vectors::{ Vector2, Vector3, Vector4 };
+use iomath::quaternions::Quaternion;
+
+const GENERATED: &str = include_str!(concat!(env!("OUT_DIR"), "/swizzles.rs"));
+
+fn generated_names(types: &str) -> (Vec<Vec<&'static str>>, Vec<Vec<&'static str>>) {
+    let block = GENERATED.split("impl_swizzles!(").find(|block| block.starts_with(types)).unwrap();
+    let (getters, setters) = block.split_once("} {").unwrap();
+    let names = |section: &'static str| section.lines()
+        .filter(|line| line.ends_with(';'))
+        .filter_map(|line| line.split_once(':'))
+        .map(|(names, _)| names.split_whitespace().collect())
+        .collect();
+
+    (names(getters), names(setters))
+}
+
+fn assert_name_sets(names: &[Vec<&str>], prefix: &str) {
+    for sets in names {
+        let xyzw = sets[0].strip_prefix(prefix).unwrap();
+        let rgba: String = xyzw.chars().map(|c| "rgba".as_bytes()["xyzw".find(c).unwrap()] as char).collect();
+        let stpq: String = xyzw.chars().map(|c| "stpq".as_bytes()["xyzw".find(c).unwrap()] as char).collect();
+
+        assert_eq!(sets[1], format!("{}{}", prefix, rgba));
+        assert_eq!(sets[2], format!("{}{}", prefix, stpq));
+    }
+}
+
+#[test]
+fn swizzles_generated_counts() {
+    let (getters, setters) = generated_names("[TVector2 ");
+    assert_eq!((getters.len(), setters.len()), (4 + 8 + 16, 2));
+    assert_name_sets(&getters, "");
+    assert_name_sets(&setters, "set_");
+
+    let (getters, setters) = generated_names("[TVector3 ");
+    assert_eq!((getters.len(), setters.len()), (9 + 27 + 81, 6 + 6));
+    assert_name_sets(&getters, "");
+    assert_name_sets(&setters, "set_");
+
+    let (getters, setters) = generated_names("[TVector4 ");
+    assert_eq!((getters.len(), setters.len()), (16 + 64 + 256, 12 + 24 + 24));
+    assert_name_sets(&getters, "");
+    assert_name_sets(&setters, "set_");
+}
+
+#[test]
+fn swizzles_vector_2_name_sets() {
+    let mut vector = Vector2::new(1.0, 2.0);
+
+    assert_eq!(vector.yx(), Vector2::new(2.0, 1.0));
+    assert_eq!(vector.gr(), vector.yx());
+    assert_eq!(vector.ts(), vector.yx());
+    assert_eq!(vector.yyxy(), Vector4::new(2.0, 2.0, 1.0, 2.0));
+    assert_eq!(vector.ggrg(), vector.yyxy());
+    assert_eq!(vector.ttst(), vector.yyxy());
+
+    vector.set_gr(Vector2::new(3.0, 4.0));
+    assert_eq!(vector, Vector2::new(4.0, 3.0));
+    vector.set_ts(Vector2::new(5.0, 6.0));
+    assert_eq!(vector, Vector2::new(6.0, 5.0));
+}
+
+#[test]
+fn swizzles_vector_3_name_sets() {
+    let mut vector = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(vector.zxy(), Vector3::new(3.0, 1.0, 2.0));
+    assert_eq!(vector.brg(), vector.zxy());
+    assert_eq!(vector.pst(), vector.zxy());
+    assert_eq!(vector.zzxy(), Vector4::new(3.0, 3.0, 1.0, 2.0));
+    assert_eq!(vector.bbrg(), vector.zzxy());
+    assert_eq!(vector.ppst(), vector.zzxy());
+
+    vector.set_bg(Vector2::new(-3.0, -2.0));
+    assert_eq!(vector, Vector3::new(1.0, -2.0, -3.0));
+    vector.set_pts(Vector3::new(6.0, 5.0, 4.0));
+    assert_eq!(vector, Vector3::new(4.0, 5.0, 6.0));
+}
+
+#[test]
+fn swizzles_vector_4_name_sets() {
+    let mut vector = Vector4::new(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(vector.wzyx(), Vector4::new(4.0, 3.0, 2.0, 1.0));
+    assert_eq!(vector.abgr(), vector.wzyx());
+    assert_eq!(vector.qpts(), vector.wzyx());
+    assert_eq!(vector.wxw(), Vector3::new(4.0, 1.0, 4.0));
+    assert_eq!(vector.ara(), vector.wxw());
+    assert_eq!(vector.qsq(), vector.wxw());
+
+    vector.set_ar(Vector2::new(-4.0, -1.0));
+    assert_eq!(vector, Vector4::new(-1.0, 2.0, 3.0, -4.0));
+    vector.set_qpts(Vector4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(vector, Vector4::new(4.0, 3.0, 2.0, 1.0));
+}
+
+#[test]
+fn swizzles_quaternion_name_sets() {
+    let mut quaternion = Quaternion::new(4.0, 1.0, 2.0, 3.0);
+
+    assert_eq!(quaternion.xyzw(), Vector4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(quaternion.rgba(), quaternion.xyzw());
+    assert_eq!(quaternion.stpq(), quaternion.xyzw());
+    assert_eq!(quaternion.zy(), Vector2::new(3.0, 2.0));
+    assert_eq!(quaternion.bg(), quaternion.zy());
+    assert_eq!(quaternion.pt(), quaternion.zy());
+
+    quaternion.set_agb(Vector3::new(-4.0, -2.0, -3.0));
+    assert_eq!(quaternion, Quaternion::new(-4.0, 1.0, -2.0, -3.0));
+    quaternion.set_qs(Vector2::new(0.0, 0.5));
+    assert_eq!(quaternion, Quaternion::new(0.0, 0.5, -2.0, -3.0));
+}
\ No newline at end of file
@@ -1,4 +1,10 @@
-use iomath::vectors::{ Vector2, UVector2, Vector3, Vector4 };
+use std::convert::TryFrom;
+use std::collections::HashMap;
+use std::cmp::Ordering;
+
+use iomath::vectors::{ Vector2, IVector2, UVector2, Vector3, Vector4 };
+use iomath::vectors::{ CastError, CastErrorKind, ParseError };
+use iomath::extended::vectors::{ BVector2, F32Vector2, F64Vector2, I8Vector2, I16Vector2, I32Vector2, I64Vector2, U8Vector2 };
 use iomath::quaternions::Quaternion;
 
 #[test]
@@ -60,10 +66,11 @@ fn vector_2_index() {
 }
 
 #[test]
+#[should_panic(expected = "index out of bounds: TVector2 has 2 components but the index is 2")]
 fn vector_2_index_out_of_bounds() {
     let vector = Vector2::new(7.3, 2.4);
-    
-    assert_eq!(vector, Vector2::new(vector[0], vector[256]))
+
+    let _ = vector[2];
 }
 
 #[test]
@@ -76,11 +83,11 @@ fn vector_2_index_mut() {
 }
 
 #[test]
+#[should_panic(expected = "index out of bounds: TVector2 has 2 components but the index is 1024")]
 fn vector_2_index_mut_out_of_bounds() {
-    let mut vector = Vector2::new(4.5, -7.6);
-    vector[1024] = -1.0;
+    let mut vector = Vector2::new(7.3, 2.4);
 
-    assert_eq!(vector, Vector2::new(4.5, -1.0));
+    vector[1024] = -1.0;
 }
 
 #[test]
@@ -419,4 +426,624 @@ fn vector_2_debug_struct() {
     let vector = Vector2::new(11.0, 4.5);
 
     assert_eq!(format!("{:?}", vector), "TVector2<f32> { x: 11.0, y: 4.5 }");
-}
\ No newline at end of file
+}
+
+#[test]
+fn vector_2_dot() {
+    let vector = Vector2::new(1.5, -2.0);
+    let result = vector.dot(Vector2::new(4.0, 0.5));
+
+    assert_eq!(result, 5.0);
+}
+
+#[test]
+fn vector_2_length_squared() {
+    let vector = Vector2::new(-6.0, 8.0);
+
+    assert_eq!(vector.length_squared(), 100.0);
+}
+
+#[test]
+fn vector_2_length() {
+    let vector = Vector2::new(-6.0, 8.0);
+
+    assert_eq!(vector.length(), 10.0);
+}
+
+#[test]
+fn vector_2_distance_squared() {
+    let vector = Vector2::new(2.0, -1.0);
+
+    assert_eq!(vector.distance_squared(Vector2::new(-4.0, 7.0)), 100.0);
+}
+
+#[test]
+fn vector_2_distance() {
+    let vector = Vector2::new(2.0, -1.0);
+
+    assert_eq!(vector.distance(Vector2::new(-4.0, 7.0)), 10.0);
+}
+
+#[test]
+fn vector_2_normalize() {
+    let vector = Vector2::new(-6.0, 8.0);
+
+    assert_eq!(vector.normalize(), Vector2::new(-0.6, 0.8));
+}
+
+#[test]
+fn vector_2_try_normalize() {
+    let vector = Vector2::new(0.0, -2.5);
+
+    assert_eq!(vector.try_normalize(), Some(Vector2::new(0.0, -1.0)));
+}
+
+#[test]
+fn vector_2_try_normalize_zero() {
+    let vector = Vector2::empty();
+
+    assert_eq!(vector.try_normalize(), None);
+}
+
+#[test]
+fn vector_2_perp() {
+    let vector = Vector2::new(2.5, -4.0);
+
+    assert_eq!(vector.perp(), Vector2::new(4.0, 2.5));
+    assert_eq!(vector.dot(vector.perp()), 0.0);
+}
+
+#[test]
+fn vector_2_perp_dot() {
+    let vector = Vector2::new(2.0, 1.0);
+
+    assert_eq!(vector.perp_dot(Vector2::new(1.0, 3.0)), 5.0);
+    assert_eq!(vector.perp_dot(Vector2::new(3.0, 1.0)), -1.0);
+}
+
+#[test]
+fn vector_2_cross() {
+    let vector = Vector2::new(2.0, 1.0);
+
+    assert_eq!(vector.cross(Vector2::new(1.0, 3.0)), 5.0);
+    assert_eq!(vector.cross(vector * 3.0), 0.0);
+}
+
+#[test]
+fn vector_2_get() {
+    let vector = Vector2::new(7.3, 2.4);
+
+    assert_eq!(vector.get(0), Some(&7.3));
+    assert_eq!(vector.get(1), Some(&2.4));
+    assert_eq!(vector.get(2), None);
+}
+
+#[test]
+fn vector_2_get_mut() {
+    let mut vector = Vector2::new(7.3, 2.4);
+    *vector.get_mut(1).unwrap() = 0.5;
+
+    assert_eq!(vector[1], 0.5);
+    assert_eq!(vector.get_mut(2), None);
+}
+
+#[test]
+fn vector_2_index_range() {
+    let vector = Vector2::new(7.3, 2.4);
+
+    assert_eq!(&vector[..], &[7.3, 2.4]);
+    assert_eq!(&vector[1..], &[2.4]);
+    assert_eq!(&vector[..1], &[7.3]);
+    assert_eq!(&vector[0..=1], &[7.3, 2.4]);
+    assert_eq!(&vector[..=1], &[7.3, 2.4]);
+    assert_eq!(&vector[1..2], &[2.4]);
+}
+
+#[test]
+fn vector_2_index_range_mut() {
+    let mut vector = Vector2::new(7.3, 2.4);
+    vector[..2].copy_from_slice(&[1.0, 2.0]);
+
+    assert_eq!(vector[0], 1.0);
+    assert_eq!(vector[1], 2.0);
+}
+
+#[test]
+#[should_panic(expected = "range end index 3 out of range for slice of length 2")]
+fn vector_2_index_range_out_of_bounds() {
+    let vector = Vector2::new(7.3, 2.4);
+
+    let _ = &vector[1..3];
+}
+
+#[test]
+fn vector_2_swizzle() {
+    let vector = Vector2::new(1.0, 2.0);
+
+    assert_eq!(vector.yx(), Vector2::new(2.0, 1.0));
+    assert_eq!(vector.xxy(), Vector3::new(1.0, 1.0, 2.0));
+    assert_eq!(vector.yyyx(), Vector4::new(2.0, 2.0, 2.0, 1.0));
+    assert_eq!(vector.gr(), vector.yx());
+    assert_eq!(vector.tsst(), Vector4::new(2.0, 1.0, 1.0, 2.0));
+}
+
+#[test]
+fn vector_2_set_swizzle() {
+    let mut vector = Vector2::new(1.0, 2.0);
+    vector.set_yx(Vector2::new(3.0, 4.0));
+
+    assert_eq!(vector, Vector2::new(4.0, 3.0));
+
+    vector.set_rg(Vector2::new(5.0, 6.0));
+    vector.set_ts(Vector2::new(7.0, 5.5));
+
+    assert_eq!(vector, Vector2::new(5.5, 7.0));
+}
+
+#[test]
+fn vector_2_comparisons() {
+    let first = Vector2::new(1.0, -2.0);
+    let second = Vector2::new(1.0, 3.0);
+
+    assert_eq!(first.cmplt(second), BVector2::new(false, true));
+    assert_eq!(first.cmple(second), BVector2::new(true, true));
+    assert_eq!(first.cmpeq(second), BVector2::new(true, false));
+    assert_eq!(first.cmpne(second), BVector2::new(false, true));
+    assert_eq!(first.cmpge(second), BVector2::new(true, false));
+    assert_eq!(first.cmpgt(second), BVector2::new(false, false));
+}
+
+#[test]
+fn vector_2_any_all_none() {
+    assert!(BVector2::new(true, false).any());
+    assert!(!BVector2::new(true, false).all());
+    assert!(!BVector2::new(true, false).none());
+    assert!(BVector2::new(true, true).all());
+    assert!(BVector2::new(false, false).none());
+    assert!(!BVector2::new(false, false).any());
+}
+
+#[test]
+fn vector_2_not() {
+    assert_eq!(!BVector2::new(true, false), BVector2::new(false, true));
+    assert_eq!(!UVector2::from_scalar(0), UVector2::from_scalar(u32::MAX));
+}
+
+#[test]
+fn vector_2_select() {
+    let first = Vector2::new(1.0, -2.0);
+    let second = Vector2::new(1.0, 3.0);
+
+    assert_eq!(Vector2::select(first.cmplt(second), first, second), Vector2::new(1.0, -2.0));
+    assert_eq!(Vector2::select(BVector2::new(true, false), first, second), Vector2::new(1.0, 3.0));
+}
+
+#[test]
+fn vector_2_abs() {
+    let vector = Vector2::new(-1.5, 2.25);
+
+    assert_eq!(vector.abs(), Vector2::new(1.5, 2.25));
+    assert_eq!(IVector2::from_scalar(-3).abs(), IVector2::from_scalar(3));
+}
+
+#[test]
+fn vector_2_min() {
+    let vector = Vector2::new(-1.5, 2.25);
+
+    assert_eq!(vector.min(Vector2::new(0.5, 3.0)), Vector2::new(-1.5, 2.25));
+}
+
+#[test]
+fn vector_2_max() {
+    let vector = Vector2::new(-1.5, 2.25);
+
+    assert_eq!(vector.max(Vector2::new(0.5, 3.0)), Vector2::new(0.5, 3.0));
+}
+
+#[test]
+fn vector_2_clamp() {
+    let vector = Vector2::new(-1.5, 2.25);
+
+    assert_eq!(vector.clamp(Vector2::from_scalar(-1.0), Vector2::from_scalar(1.0)), Vector2::new(-1.0, 1.0));
+}
+
+#[test]
+fn vector_2_floor() {
+    let vector = Vector2::new(-2.5, 1.5);
+
+    assert_eq!(vector.floor(), Vector2::new(-3.0, 1.0));
+}
+
+#[test]
+fn vector_2_ceil() {
+    let vector = Vector2::new(-2.5, 1.5);
+
+    assert_eq!(vector.ceil(), Vector2::new(-2.0, 2.0));
+}
+
+#[test]
+fn vector_2_round() {
+    let vector = Vector2::new(-2.5, 1.5);
+
+    assert_eq!(vector.round(), Vector2::new(-3.0, 2.0));
+}
+
+#[test]
+fn vector_2_trunc() {
+    let vector = Vector2::new(-2.5, 1.5);
+
+    assert_eq!(vector.trunc(), Vector2::new(-2.0, 1.0));
+}
+
+#[test]
+fn vector_2_fract() {
+    let vector = Vector2::new(-2.5, 1.5);
+
+    assert_eq!(vector.fract(), Vector2::new(-0.5, 0.5));
+}
+
+#[test]
+fn vector_2_signum() {
+    let vector = Vector2::new(-2.5, 1.5);
+
+    assert_eq!(vector.signum(), Vector2::new(-1.0, 1.0));
+    assert_eq!(IVector2::from_scalar(0).signum(), IVector2::from_scalar(0));
+}
+
+#[test]
+fn vector_2_copysign() {
+    let vector = Vector2::new(-1.5, 2.25);
+
+    assert_eq!(vector.copysign(Vector2::new(0.5, 3.0)), Vector2::new(1.5, 2.25));
+}
+
+#[test]
+fn vector_2_recip() {
+    let vector = Vector2::new(4.0, 0.25);
+
+    assert_eq!(vector.recip(), Vector2::new(0.25, 4.0));
+}
+
+#[test]
+fn vector_2_sqrt() {
+    let vector = Vector2::new(4.0, 0.25);
+
+    assert_eq!(vector.sqrt(), Vector2::new(2.0, 0.5));
+}
+
+#[test]
+fn vector_2_powf() {
+    let vector = Vector2::new(4.0, 0.25);
+
+    assert_eq!(vector.powf(0.5), vector.sqrt());
+    assert_eq!(vector.powf(2.0), vector * vector);
+}
+
+#[test]
+fn vector_2_exp() {
+    let vector = Vector2::from_scalar(0.0);
+
+    assert_eq!(vector.exp(), Vector2::from_scalar(1.0));
+    assert!((Vector2::from_scalar(1.0).exp() - Vector2::from_scalar(std::f32::consts::E)).abs().max_element() < 1e-6);
+}
+
+#[test]
+fn vector_2_ln() {
+    let vector = Vector2::from_scalar(1.0);
+
+    assert_eq!(vector.ln(), Vector2::from_scalar(0.0));
+    assert!((Vector2::from_scalar(std::f32::consts::E).ln() - vector).abs().max_element() < 1e-6);
+}
+
+#[test]
+fn vector_2_mul_add() {
+    let vector = Vector2::new(-1.5, 2.25);
+
+    assert_eq!(vector.mul_add(Vector2::new(0.5, 3.0), Vector2::new(-2.5, 1.5)), Vector2::new(-3.25, 8.25));
+}
+
+#[test]
+fn vector_2_min_element() {
+    let vector = Vector2::new(0.5, 3.0);
+
+    assert_eq!(vector.min_element(), 0.5);
+}
+
+#[test]
+fn vector_2_max_element() {
+    let vector = Vector2::new(0.5, 3.0);
+
+    assert_eq!(vector.max_element(), 3.0);
+}
+
+#[test]
+fn vector_2_sum() {
+    let vector = Vector2::new(0.5, 3.0);
+
+    assert_eq!(vector.sum(), 3.5);
+}
+
+#[test]
+fn vector_2_product() {
+    let vector = Vector2::new(0.5, 3.0);
+
+    assert_eq!(vector.product(), 1.5);
+}
+
+#[test]
+fn vector_2_reflect() {
+    let vector = Vector2::new(1.0, -1.0);
+
+    assert_eq!(vector.reflect(Vector2::new(0.0, 1.0)), Vector2::new(1.0, 1.0));
+}
+
+#[test]
+fn vector_2_refract() {
+    let vector = Vector2::new(0.6, -0.8);
+    let normal = Vector2::new(0.0, 1.0);
+    let refracted = vector.refract(normal, 0.5).unwrap();
+
+    assert_eq!(vector.refract(normal, 1.0), Some(vector));
+    assert!((refracted.x - 0.3).abs() < 1e-6);
+    assert!((refracted.length() - 1.0).abs() < 1e-6);
+    assert!(refracted.y < 0.0);
+    assert_eq!(vector.refract(normal, 2.0), None);
+}
+
+#[test]
+fn vector_2_project_onto() {
+    let vector = Vector2::new(2.0, 3.0);
+
+    assert_eq!(vector.project_onto(Vector2::new(0.0, 2.0)), Vector2::new(0.0, 3.0));
+}
+
+#[test]
+fn vector_2_reject_from() {
+    let vector = Vector2::new(2.0, 3.0);
+
+    assert_eq!(vector.reject_from(Vector2::new(0.0, 2.0)), Vector2::new(2.0, 0.0));
+}
+
+#[test]
+fn vector_2_face_forward() {
+    let normal = Vector2::new(0.0, 1.0);
+
+    assert_eq!(normal.face_forward(Vector2::new(0.0, -1.0), normal), normal);
+    assert_eq!(normal.face_forward(Vector2::new(0.0, 1.0), normal), -normal);
+}
+
+#[test]
+fn vector_2_angle_between() {
+    let vector = Vector2::new(1.0, 0.0);
+
+    assert!((vector.angle_between(Vector2::new(0.0, 1.0)) - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    assert!((vector.angle_between(Vector2::new(-1.0, 0.0)) - std::f32::consts::PI).abs() < 1e-6);
+    assert_eq!(vector.angle_between(vector * 3.0), 0.0);
+    assert!((F64Vector2::new(1.0, 0.0).angle_between(F64Vector2::new(1.0, 1e-07)) - 1e-7).abs() < 1e-15);
+}
+
+#[test]
+fn vector_2_as() {
+    let vector = Vector2::new(1.75, -2.5);
+
+    assert_eq!(vector.as_i32(), IVector2::new(1, -2));
+    assert_eq!(vector.as_u8(), U8Vector2 { x: 1, y: 0 });
+    assert_eq!(IVector2::new(-3, 7).as_f64(), F64Vector2::new(-3.0, 7.0));
+}
+
+#[test]
+fn vector_2_from_lossless() {
+    assert_eq!(I32Vector2::from(I16Vector2::new(-3, 7)), I32Vector2::new(-3, 7));
+    assert_eq!(F32Vector2::from(U8Vector2 { x: 1, y: 2 }), F32Vector2::new(1.0, 2.0));
+    assert_eq!(F64Vector2::from(Vector2::new(1.75, -2.5)), F64Vector2::new(1.75, -2.5));
+}
+
+#[test]
+fn vector_2_try_from() {
+    assert_eq!(U8Vector2::try_from(IVector2::new(1, 2)), Ok(U8Vector2 { x: 1, y: 2 }));
+    assert_eq!(U8Vector2::try_from(IVector2::new(1, 256)), Err(CastError { component: 1, kind: CastErrorKind::Overflow }));
+    assert_eq!(I8Vector2::try_from(IVector2::new(-129, 0)), Err(CastError { component: 0, kind: CastErrorKind::Overflow }));
+    assert_eq!(IVector2::try_from(Vector2::new(1.0, -2.0)), Ok(IVector2::new(1, -2)));
+    assert_eq!(IVector2::try_from(Vector2::new(1.0, -2.5)), Err(CastError { component: 1, kind: CastErrorKind::PrecisionLoss }));
+    assert_eq!(IVector2::try_from(Vector2::new(1e10 + 0.5, 0.0)), Err(CastError { component: 0, kind: CastErrorKind::Overflow }));
+    assert_eq!(IVector2::try_from(Vector2::new(1.0, f32::NAN)), Err(CastError { component: 1, kind: CastErrorKind::NonFinite }));
+    assert_eq!(IVector2::try_from(Vector2::new(2147483648.0, 0.0)), Err(CastError { component: 0, kind: CastErrorKind::Overflow }));
+    assert_eq!(Vector2::try_from(F64Vector2::new(1e300, 0.0)), Err(CastError { component: 0, kind: CastErrorKind::Overflow }));
+    assert_eq!(Vector2::try_from(F64Vector2::new(0.5, -2.0)), Ok(Vector2::new(0.5, -2.0)));
+    assert_eq!(Vector2::try_from(F64Vector2::new(16777217.0, 0.0)), Err(CastError { component: 0, kind: CastErrorKind::PrecisionLoss }));
+    assert_eq!(Vector2::try_from(IVector2::new(16_777_216, -3)), Ok(Vector2::new(16777216.0, -3.0)));
+    assert_eq!(Vector2::try_from(IVector2::new(16_777_217, 0)), Err(CastError { component: 0, kind: CastErrorKind::PrecisionLoss }));
+    assert_eq!(F64Vector2::try_from(I64Vector2::new(i64::MIN, i64::MAX)), Err(CastError { component: 1, kind: CastErrorKind::PrecisionLoss }));
+}
+
+#[test]
+fn vector_2_from_array() {
+    let vector = Vector2::from([1.0, -2.0]);
+
+    assert_eq!(vector, Vector2::new(1.0, -2.0));
+}
+
+#[test]
+fn vector_2_into_array() {
+    let array: [f32; 2] = Vector2::new(1.0, -2.0).into();
+
+    assert_eq!(array, [1.0, -2.0]);
+}
+
+#[test]
+fn vector_2_from_tuple() {
+    let vector = Vector2::from((1.0, -2.0));
+
+    assert_eq!(vector, Vector2::new(1.0, -2.0));
+}
+
+#[test]
+fn vector_2_into_tuple() {
+    let tuple: (f32, f32) = Vector2::new(1.0, -2.0).into();
+
+    assert_eq!(tuple, (1.0, -2.0));
+}
+
+#[test]
+fn vector_2_as_array() {
+    let vector = Vector2::new(1.0, -2.0);
+
+    assert_eq!(vector.as_array(), &[1.0, -2.0]);
+    assert_eq!(std::mem::size_of::<Vector2>(), std::mem::size_of::<[f32; 2]>());
+}
+
+#[test]
+fn vector_2_as_mut_array() {
+    let mut vector = Vector2::new(1.0, -2.0);
+    vector.as_mut_array()[1] = 8.0;
+
+    assert_eq!(vector[1], 8.0);
+}
+
+#[test]
+fn vector_2_from_slice() {
+    let vector = Vector2::from_slice(&[1.0, -2.0, 9.0]);
+
+    assert_eq!(vector, Vector2::new(1.0, -2.0));
+}
+
+#[test]
+#[should_panic]
+fn vector_2_from_slice_too_short() {
+    let _ = Vector2::from_slice(&[1.0; 1]);
+}
+
+#[test]
+fn vector_2_write_to_slice() {
+    let mut buffer = [0.0; 3];
+    Vector2::new(1.0, -2.0).write_to_slice(&mut buffer[1..]);
+
+    assert_eq!(buffer, [0.0, 1.0, -2.0]);
+}
+
+#[test]
+fn vector_2_iter() {
+    let vector = Vector2::new(1.0, -2.0);
+
+    assert_eq!(vector.iter().copied().collect::<Vec<f32>>(), vec![1.0, -2.0]);
+}
+
+#[test]
+fn vector_2_iter_mut() {
+    let mut vector = Vector2::new(1.0, -2.0);
+    for component in vector.iter_mut() {
+        *component *= 2.0;
+    }
+
+    assert_eq!(vector, Vector2::new(1.0, -2.0) * 2.0);
+}
+
+#[test]
+fn vector_2_into_iter() {
+    let mut vector = Vector2::new(1.0, -2.0);
+    for component in &mut vector {
+        *component += 1.0;
+    }
+
+    assert_eq!((&vector).into_iter().count(), 2);
+    assert_eq!(vector.into_iter().collect::<Vec<f32>>(), vec![2.0, -1.0]);
+}
+
+#[test]
+fn vector_2_from_iter() {
+    let vector: Vector2 = (1..=2).map(|i| i as f32).collect();
+
+    assert_eq!(vector, Vector2::new(1.0, 2.0));
+}
+
+#[test]
+#[should_panic(expected = "TVector2 must be collected from exactly 2 components")]
+fn vector_2_from_iter_wrong_length() {
+    let _: Vector2 = (0..3).map(|i| i as f32).collect();
+}
+
+#[test]
+fn vector_2_try_from_iter() {
+    assert_eq!(Vector2::try_from_iter(vec![1.0, -2.0]), Some(Vector2::new(1.0, -2.0)));
+    assert_eq!(Vector2::try_from_iter(vec![1.0; 1]), None);
+    assert_eq!(Vector2::try_from_iter(vec![1.0; 3]), None);
+}
+
+#[test]
+fn vector_2_iter_sum() {
+    let points = [Vector2::new(1.0, -2.0), Vector2::from_scalar(1.0), Vector2::from_scalar(-3.0)];
+
+    assert_eq!(points.iter().sum::<Vector2>(), Vector2::new(1.0, -2.0) - 2.0);
+    assert_eq!(points.iter().copied().sum::<Vector2>(), Vector2::new(1.0, -2.0) - 2.0);
+    assert_eq!(Vec::<Vector2>::new().into_iter().sum::<Vector2>(), Vector2::empty());
+}
+
+#[test]
+fn vector_2_iter_product() {
+    let factors = [Vector2::new(1.0, -2.0), Vector2::from_scalar(2.0)];
+
+    assert_eq!(factors.iter().product::<Vector2>(), Vector2::new(1.0, -2.0) * 2.0);
+    assert_eq!(factors.iter().copied().product::<Vector2>(), Vector2::new(1.0, -2.0) * 2.0);
+    assert_eq!(Vec::<Vector2>::new().into_iter().product::<Vector2>(), Vector2::from_scalar(1.0));
+}
+
+#[test]
+fn vector_2_default() {
+    assert_eq!(Vector2::default(), Vector2::empty());
+}
+
+#[test]
+fn vector_2_hash() {
+    let mut cells = HashMap::new();
+    cells.insert(IVector2::new(1, -2), "filled");
+
+    assert_eq!(cells.get(&IVector2::new(1, -2)), Some(&"filled"));
+    assert_eq!(cells.get(&IVector2::from_scalar(0)), None);
+}
+
+#[test]
+fn vector_2_partial_ord() {
+    assert!(IVector2::new(1, -2) < IVector2::new(1, 100));
+    assert!(IVector2::new(1, -2) > IVector2::new(0, 100));
+    assert_eq!(BVector2::new(false, true).partial_cmp(&BVector2::new(true, false)), Some(Ordering::Less));
+}
+
+#[test]
+fn vector_2_ord() {
+    let mut vectors = vec![IVector2::from_scalar(2), IVector2::new(1, -2), IVector2::from_scalar(-1)];
+    vectors.sort();
+
+    assert_eq!(vectors, vec![IVector2::from_scalar(-1), IVector2::new(1, -2), IVector2::from_scalar(2)]);
+    assert_eq!(IVector2::new(1, -2).cmp(&IVector2::new(1, -2)), Ordering::Equal);
+}
+
+#[test]
+fn vector_2_ord_min_max() {
+    let first = IVector2::new(1, 5);
+    let second = IVector2::new(2, -3);
+
+    assert_eq!(first.max(second), IVector2::new(2, 5));
+    assert_eq!(first.min(second), IVector2::new(1, -3));
+    assert_eq!(Ord::max(first, second), second);
+    assert_eq!(Ord::min(first, second), first);
+    assert_eq!(Ord::clamp(second, first, first), first);
+}
+
+#[test]
+fn vector_2_display() {
+    let vector = Vector2::new(1.0, -2.5);
+
+    assert_eq!(format!("{}", vector), "(1, -2.5)");
+    assert_eq!(format!("{:.2}", vector), "(1.00, -2.50)");
+}
+
+#[test]
+fn vector_2_from_str() {
+    assert_eq!("(1, -2.5)".parse(), Ok(Vector2::new(1.0, -2.5)));
+    assert_eq!(" 1.0 -2.5 ".parse(), Ok(Vector2::new(1.0, -2.5)));
+    assert_eq!("(1.0 -2.5)".parse(), Ok(Vector2::new(1.0, -2.5)));
+    assert_eq!("1, -2".replace(",", " ").parse(), Ok(IVector2::new(1, -2)));
+    assert_eq!("(1.0 -2.5".parse::<Vector2>(), Err(ParseError::UnbalancedParentheses));
+    assert_eq!("(1, 2, 3, 4, 5)".parse::<Vector2>(), Err(ParseError::WrongComponentCount { expected: 2, found: 5 }));
+    assert_eq!("1, x".parse::<Vector2>(), Err(ParseError::InvalidComponent(1)));
+}
@@ -1,4 +1,10 @@
-use iomath::vectors::{ Vector3, UVector3, Vector4, Vector2 };
+use std::convert::TryFrom;
+use std::collections::HashMap;
+use std::cmp::Ordering;
+
+use iomath::vectors::{ Vector3, IVector3, UVector3, Vector4, Vector2 };
+use iomath::vectors::{ CastError, CastErrorKind, ParseError };
+use iomath::extended::vectors::{ BVector3, F32Vector3, F64Vector3, I8Vector3, I16Vector3, I32Vector3, U8Vector3 };
 use iomath::quaternions::Quaternion;
 
 #[test]
@@ -74,10 +80,11 @@ fn vector_3_index() {
 }
 
 #[test]
+#[should_panic(expected = "index out of bounds: TVector3 has 3 components but the index is 3")]
 fn vector_3_index_out_of_bounds() {
     let vector = Vector3::new(7.3, 2.4, -3.8);
-    
-    assert_eq!(vector, Vector3::new(vector[0], vector[1], vector[256]))
+
+    let _ = vector[3];
 }
 
 #[test]
@@ -91,11 +98,11 @@ fn vector_3_index_mut() {
 }
 
 #[test]
+#[should_panic(expected = "index out of bounds: TVector3 has 3 components but the index is 1024")]
 fn vector_3_index_mut_out_of_bounds() {
-    let mut vector = Vector3::new(4.5, -7.6, 7.2);
-    vector[1024] = -1.0;
+    let mut vector = Vector3::new(7.3, 2.4, -3.8);
 
-    assert_eq!(vector, Vector3::new(4.5, -7.6, -1.0));
+    vector[1024] = -1.0;
 }
 
 #[test]
@@ -434,4 +441,636 @@ fn vector_3_debug_struct() {
     let vector = Vector3::new(11.0, 4.5, -9.0);
 
     assert_eq!(format!("{:?}", vector), "TVector3<f32> { x: 11.0, y: 4.5, z: -9.0 }");
-}
\ No newline at end of file
+}
+
+#[test]
+fn vector_3_dot() {
+    let vector = Vector3::new(1.5, -2.0, 3.0);
+    let result = vector.dot(Vector3::new(4.0, 0.5, -1.0));
+
+    assert_eq!(result, 2.0);
+}
+
+#[test]
+fn vector_3_length_squared() {
+    let vector = Vector3::new(2.0, -3.0, 6.0);
+
+    assert_eq!(vector.length_squared(), 49.0);
+}
+
+#[test]
+fn vector_3_length() {
+    let vector = Vector3::new(2.0, -3.0, 6.0);
+
+    assert_eq!(vector.length(), 7.0);
+}
+
+#[test]
+fn vector_3_distance_squared() {
+    let vector = Vector3::new(1.0, 5.0, -2.0);
+
+    assert_eq!(vector.distance_squared(Vector3::new(3.0, 2.0, 4.0)), 49.0);
+}
+
+#[test]
+fn vector_3_distance() {
+    let vector = Vector3::new(1.0, 5.0, -2.0);
+
+    assert_eq!(vector.distance(Vector3::new(3.0, 2.0, 4.0)), 7.0);
+}
+
+#[test]
+fn vector_3_normalize() {
+    let vector = Vector3::new(0.0, -3.0, 4.0);
+
+    assert_eq!(vector.normalize(), Vector3::new(0.0, -0.6, 0.8));
+}
+
+#[test]
+fn vector_3_try_normalize() {
+    let vector = Vector3::new(0.0, 0.0, 7.5);
+
+    assert_eq!(vector.try_normalize(), Some(Vector3::new(0.0, 0.0, 1.0)));
+}
+
+#[test]
+fn vector_3_try_normalize_zero() {
+    let vector = Vector3::empty();
+
+    assert_eq!(vector.try_normalize(), None);
+}
+
+#[test]
+fn vector_3_cross() {
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+    let result = vector.cross(Vector3::new(4.0, 5.0, 6.0));
+
+    assert_eq!(result, Vector3::new(-3.0, 6.0, -3.0));
+}
+
+#[test]
+fn vector_3_cross_anticommutative() {
+    let first = Vector3::new(1.0, -2.0, 0.5);
+    let second = Vector3::new(3.0, 4.0, -1.0);
+
+    assert_eq!(first.cross(second), -second.cross(first));
+}
+
+#[test]
+fn vector_3_scalar_triple() {
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+    let result = vector.scalar_triple(Vector3::new(0.0, 1.0, 4.0), Vector3::new(5.0, 6.0, 0.0));
+
+    assert_eq!(result, 1.0);
+}
+
+#[test]
+fn vector_3_vector_triple() {
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+    let b = Vector3::new(0.0, 1.0, 4.0);
+    let c = Vector3::new(5.0, 6.0, 0.0);
+
+    assert_eq!(vector.vector_triple(b, c), b * vector.dot(c) - c * vector.dot(b));
+}
+
+#[test]
+fn vector_3_get() {
+    let vector = Vector3::new(7.3, 2.4, -3.8);
+
+    assert_eq!(vector.get(0), Some(&7.3));
+    assert_eq!(vector.get(2), Some(&-3.8));
+    assert_eq!(vector.get(3), None);
+}
+
+#[test]
+fn vector_3_get_mut() {
+    let mut vector = Vector3::new(7.3, 2.4, -3.8);
+    *vector.get_mut(1).unwrap() = 0.5;
+
+    assert_eq!(vector[1], 0.5);
+    assert_eq!(vector.get_mut(3), None);
+}
+
+#[test]
+fn vector_3_index_range() {
+    let vector = Vector3::new(7.3, 2.4, -3.8);
+
+    assert_eq!(&vector[..], &[7.3, 2.4, -3.8]);
+    assert_eq!(&vector[1..], &[2.4, -3.8]);
+    assert_eq!(&vector[..1], &[7.3]);
+    assert_eq!(&vector[0..=1], &[7.3, 2.4]);
+    assert_eq!(&vector[..=2], &[7.3, 2.4, -3.8]);
+    assert_eq!(&vector[1..3], &[2.4, -3.8]);
+}
+
+#[test]
+fn vector_3_index_range_mut() {
+    let mut vector = Vector3::new(7.3, 2.4, -3.8);
+    vector[..2].copy_from_slice(&[1.0, 2.0]);
+
+    assert_eq!(vector[0], 1.0);
+    assert_eq!(vector[1], 2.0);
+    assert_eq!(vector[2], -3.8);
+}
+
+#[test]
+#[should_panic(expected = "range end index 4 out of range for slice of length 3")]
+fn vector_3_index_range_out_of_bounds() {
+    let vector = Vector3::new(7.3, 2.4, -3.8);
+
+    let _ = &vector[1..4];
+}
+
+#[test]
+fn vector_3_swizzle() {
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(vector.xz(), Vector2::new(1.0, 3.0));
+    assert_eq!(vector.zyx(), Vector3::new(3.0, 2.0, 1.0));
+    assert_eq!(vector.xxyz(), Vector4::new(1.0, 1.0, 2.0, 3.0));
+    assert_eq!(vector.bgr(), vector.zyx());
+    assert_eq!(vector.pts(), vector.zyx());
+}
+
+#[test]
+fn vector_3_set_swizzle() {
+    let mut vector = Vector3::new(1.0, 2.0, 3.0);
+    vector.set_xz(Vector2::new(-1.0, -3.0));
+
+    assert_eq!(vector, Vector3::new(-1.0, 2.0, -3.0));
+
+    vector.set_zxy(Vector3::new(4.0, 5.0, 6.0));
+
+    assert_eq!(vector, Vector3::new(5.0, 6.0, 4.0));
+
+    vector.set_gb(Vector2::new(0.0, 0.5));
+    vector.set_sp(Vector2::new(1.5, 2.5));
+
+    assert_eq!(vector, Vector3::new(1.5, 0.0, 2.5));
+}
+
+#[test]
+fn vector_3_comparisons() {
+    let first = Vector3::new(1.0, -2.0, 5.0);
+    let second = Vector3::new(1.0, 3.0, 4.0);
+
+    assert_eq!(first.cmplt(second), BVector3::new(false, true, false));
+    assert_eq!(first.cmple(second), BVector3::new(true, true, false));
+    assert_eq!(first.cmpeq(second), BVector3::new(true, false, false));
+    assert_eq!(first.cmpne(second), BVector3::new(false, true, true));
+    assert_eq!(first.cmpge(second), BVector3::new(true, false, true));
+    assert_eq!(first.cmpgt(second), BVector3::new(false, false, true));
+}
+
+#[test]
+fn vector_3_any_all_none() {
+    assert!(BVector3::new(true, false, false).any());
+    assert!(!BVector3::new(true, false, false).all());
+    assert!(!BVector3::new(true, false, false).none());
+    assert!(BVector3::new(true, true, true).all());
+    assert!(BVector3::new(false, false, false).none());
+    assert!(!BVector3::new(false, false, false).any());
+}
+
+#[test]
+fn vector_3_not() {
+    assert_eq!(!BVector3::new(true, false, false), BVector3::new(false, true, true));
+    assert_eq!(!UVector3::from_scalar(0), UVector3::from_scalar(u32::MAX));
+}
+
+#[test]
+fn vector_3_select() {
+    let first = Vector3::new(1.0, -2.0, 5.0);
+    let second = Vector3::new(1.0, 3.0, 4.0);
+
+    assert_eq!(Vector3::select(first.cmplt(second), first, second), Vector3::new(1.0, -2.0, 4.0));
+    assert_eq!(Vector3::select(BVector3::new(true, false, false), first, second), Vector3::new(1.0, 3.0, 4.0));
+}
+
+#[test]
+fn vector_3_abs() {
+    let vector = Vector3::new(-1.5, 2.25, -0.75);
+
+    assert_eq!(vector.abs(), Vector3::new(1.5, 2.25, 0.75));
+    assert_eq!(IVector3::from_scalar(-3).abs(), IVector3::from_scalar(3));
+}
+
+#[test]
+fn vector_3_min() {
+    let vector = Vector3::new(-1.5, 2.25, -0.75);
+
+    assert_eq!(vector.min(Vector3::new(0.5, 3.0, -2.0)), Vector3::new(-1.5, 2.25, -2.0));
+}
+
+#[test]
+fn vector_3_max() {
+    let vector = Vector3::new(-1.5, 2.25, -0.75);
+
+    assert_eq!(vector.max(Vector3::new(0.5, 3.0, -2.0)), Vector3::new(0.5, 3.0, -0.75));
+}
+
+#[test]
+fn vector_3_clamp() {
+    let vector = Vector3::new(-1.5, 2.25, -0.75);
+
+    assert_eq!(vector.clamp(Vector3::from_scalar(-1.0), Vector3::from_scalar(1.0)), Vector3::new(-1.0, 1.0, -0.75));
+}
+
+#[test]
+fn vector_3_floor() {
+    let vector = Vector3::new(-2.5, 1.5, 0.25);
+
+    assert_eq!(vector.floor(), Vector3::new(-3.0, 1.0, 0.0));
+}
+
+#[test]
+fn vector_3_ceil() {
+    let vector = Vector3::new(-2.5, 1.5, 0.25);
+
+    assert_eq!(vector.ceil(), Vector3::new(-2.0, 2.0, 1.0));
+}
+
+#[test]
+fn vector_3_round() {
+    let vector = Vector3::new(-2.5, 1.5, 0.25);
+
+    assert_eq!(vector.round(), Vector3::new(-3.0, 2.0, 0.0));
+}
+
+#[test]
+fn vector_3_trunc() {
+    let vector = Vector3::new(-2.5, 1.5, 0.25);
+
+    assert_eq!(vector.trunc(), Vector3::new(-2.0, 1.0, 0.0));
+}
+
+#[test]
+fn vector_3_fract() {
+    let vector = Vector3::new(-2.5, 1.5, 0.25);
+
+    assert_eq!(vector.fract(), Vector3::new(-0.5, 0.5, 0.25));
+}
+
+#[test]
+fn vector_3_signum() {
+    let vector = Vector3::new(-2.5, 1.5, 0.25);
+
+    assert_eq!(vector.signum(), Vector3::new(-1.0, 1.0, 1.0));
+    assert_eq!(IVector3::from_scalar(0).signum(), IVector3::from_scalar(0));
+}
+
+#[test]
+fn vector_3_copysign() {
+    let vector = Vector3::new(-1.5, 2.25, -0.75);
+
+    assert_eq!(vector.copysign(Vector3::new(0.5, 3.0, -2.0)), Vector3::new(1.5, 2.25, -0.75));
+}
+
+#[test]
+fn vector_3_recip() {
+    let vector = Vector3::new(4.0, 0.25, 16.0);
+
+    assert_eq!(vector.recip(), Vector3::new(0.25, 4.0, 0.0625));
+}
+
+#[test]
+fn vector_3_sqrt() {
+    let vector = Vector3::new(4.0, 0.25, 16.0);
+
+    assert_eq!(vector.sqrt(), Vector3::new(2.0, 0.5, 4.0));
+}
+
+#[test]
+fn vector_3_powf() {
+    let vector = Vector3::new(4.0, 0.25, 16.0);
+
+    assert_eq!(vector.powf(0.5), vector.sqrt());
+    assert_eq!(vector.powf(2.0), vector * vector);
+}
+
+#[test]
+fn vector_3_exp() {
+    let vector = Vector3::from_scalar(0.0);
+
+    assert_eq!(vector.exp(), Vector3::from_scalar(1.0));
+    assert!((Vector3::from_scalar(1.0).exp() - Vector3::from_scalar(std::f32::consts::E)).abs().max_element() < 1e-6);
+}
+
+#[test]
+fn vector_3_ln() {
+    let vector = Vector3::from_scalar(1.0);
+
+    assert_eq!(vector.ln(), Vector3::from_scalar(0.0));
+    assert!((Vector3::from_scalar(std::f32::consts::E).ln() - vector).abs().max_element() < 1e-6);
+}
+
+#[test]
+fn vector_3_mul_add() {
+    let vector = Vector3::new(-1.5, 2.25, -0.75);
+
+    assert_eq!(vector.mul_add(Vector3::new(0.5, 3.0, -2.0), Vector3::new(-2.5, 1.5, 0.25)), Vector3::new(-3.25, 8.25, 1.75));
+}
+
+#[test]
+fn vector_3_min_element() {
+    let vector = Vector3::new(0.5, 3.0, -2.0);
+
+    assert_eq!(vector.min_element(), -2.0);
+}
+
+#[test]
+fn vector_3_max_element() {
+    let vector = Vector3::new(0.5, 3.0, -2.0);
+
+    assert_eq!(vector.max_element(), 3.0);
+}
+
+#[test]
+fn vector_3_sum() {
+    let vector = Vector3::new(0.5, 3.0, -2.0);
+
+    assert_eq!(vector.sum(), 1.5);
+}
+
+#[test]
+fn vector_3_product() {
+    let vector = Vector3::new(0.5, 3.0, -2.0);
+
+    assert_eq!(vector.product(), -3.0);
+}
+
+#[test]
+fn vector_3_reflect() {
+    let vector = Vector3::new(1.0, -1.0, 0.0);
+
+    assert_eq!(vector.reflect(Vector3::new(0.0, 1.0, 0.0)), Vector3::new(1.0, 1.0, 0.0));
+}
+
+#[test]
+fn vector_3_refract() {
+    let vector = Vector3::new(0.6, -0.8, 0.0);
+    let normal = Vector3::new(0.0, 1.0, 0.0);
+    let refracted = vector.refract(normal, 0.5).unwrap();
+
+    assert_eq!(vector.refract(normal, 1.0), Some(vector));
+    assert!((refracted.x - 0.3).abs() < 1e-6);
+    assert!((refracted.length() - 1.0).abs() < 1e-6);
+    assert!(refracted.y < 0.0);
+    assert_eq!(vector.refract(normal, 2.0), None);
+}
+
+#[test]
+fn vector_3_project_onto() {
+    let vector = Vector3::new(2.0, 3.0, 4.0);
+
+    assert_eq!(vector.project_onto(Vector3::new(0.0, 2.0, 0.0)), Vector3::new(0.0, 3.0, 0.0));
+}
+
+#[test]
+fn vector_3_reject_from() {
+    let vector = Vector3::new(2.0, 3.0, 4.0);
+
+    assert_eq!(vector.reject_from(Vector3::new(0.0, 2.0, 0.0)), Vector3::new(2.0, 0.0, 4.0));
+}
+
+#[test]
+fn vector_3_face_forward() {
+    let normal = Vector3::new(0.0, 1.0, 0.0);
+
+    assert_eq!(normal.face_forward(Vector3::new(0.0, -1.0, 0.0), normal), normal);
+    assert_eq!(normal.face_forward(Vector3::new(0.0, 1.0, 0.0), normal), -normal);
+}
+
+#[test]
+fn vector_3_angle_between() {
+    let vector = Vector3::new(1.0, 0.0, 0.0);
+
+    assert!((vector.angle_between(Vector3::new(0.0, 1.0, 0.0)) - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    assert!((vector.angle_between(Vector3::new(-1.0, 0.0, 0.0)) - std::f32::consts::PI).abs() < 1e-6);
+    assert_eq!(vector.angle_between(vector * 3.0), 0.0);
+    assert!((F64Vector3::new(1.0, 0.0, 0.0).angle_between(F64Vector3::new(1.0, 1e-07, 0.0)) - 1e-7).abs() < 1e-15);
+}
+
+#[test]
+fn vector_3_as() {
+    let vector = Vector3::new(1.75, -2.5, 300.0);
+
+    assert_eq!(vector.as_i32(), IVector3::new(1, -2, 300));
+    assert_eq!(vector.as_u8(), U8Vector3 { x: 1, y: 0, z: 255 });
+    assert_eq!(IVector3::new(-3, 7, 1000).as_f64(), F64Vector3::new(-3.0, 7.0, 1000.0));
+}
+
+#[test]
+fn vector_3_from_lossless() {
+    assert_eq!(I32Vector3::from(I16Vector3::new(-3, 7, 1000)), I32Vector3::new(-3, 7, 1000));
+    assert_eq!(F32Vector3::from(U8Vector3 { x: 1, y: 2, z: 3 }), F32Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(F64Vector3::from(Vector3::new(1.75, -2.5, 300.0)), F64Vector3::new(1.75, -2.5, 300.0));
+}
+
+#[test]
+fn vector_3_try_from() {
+    assert_eq!(U8Vector3::try_from(IVector3::new(1, 2, 3)), Ok(U8Vector3 { x: 1, y: 2, z: 3 }));
+    assert_eq!(U8Vector3::try_from(IVector3::new(1, 2, 256)), Err(CastError { component: 2, kind: CastErrorKind::Overflow }));
+    assert_eq!(I8Vector3::try_from(IVector3::new(-129, 0, 0)), Err(CastError { component: 0, kind: CastErrorKind::Overflow }));
+    assert_eq!(IVector3::try_from(Vector3::new(1.0, -2.0, 300.0)), Ok(IVector3::new(1, -2, 300)));
+    assert_eq!(IVector3::try_from(Vector3::new(1.75, -2.0, 300.0)), Err(CastError { component: 0, kind: CastErrorKind::PrecisionLoss }));
+    assert_eq!(IVector3::try_from(Vector3::new(1.0, f32::NAN, f32::INFINITY)), Err(CastError { component: 1, kind: CastErrorKind::NonFinite }));
+    assert_eq!(IVector3::try_from(Vector3::new(2147483648.0, 0.0, 0.0)), Err(CastError { component: 0, kind: CastErrorKind::Overflow }));
+    assert_eq!(Vector3::try_from(F64Vector3::new(1e300, 0.0, 0.0)), Err(CastError { component: 0, kind: CastErrorKind::Overflow }));
+    assert_eq!(Vector3::try_from(F64Vector3::new(0.5, -2.0, 1048576.0)), Ok(Vector3::new(0.5, -2.0, 1048576.0)));
+    assert_eq!(Vector3::try_from(F64Vector3::new(0.0, 1e-50, 0.0)), Err(CastError { component: 1, kind: CastErrorKind::PrecisionLoss }));
+    assert_eq!(Vector3::try_from(IVector3::new(16_777_216, -3, 0)), Ok(Vector3::new(16777216.0, -3.0, 0.0)));
+    assert_eq!(Vector3::try_from(IVector3::new(0, 16_777_217, 0)), Err(CastError { component: 1, kind: CastErrorKind::PrecisionLoss }));
+}
+
+#[test]
+fn vector_3_from_array() {
+    let vector = Vector3::from([1.0, -2.0, 3.5]);
+
+    assert_eq!(vector, Vector3::new(1.0, -2.0, 3.5));
+}
+
+#[test]
+fn vector_3_into_array() {
+    let array: [f32; 3] = Vector3::new(1.0, -2.0, 3.5).into();
+
+    assert_eq!(array, [1.0, -2.0, 3.5]);
+}
+
+#[test]
+fn vector_3_from_tuple() {
+    let vector = Vector3::from((1.0, -2.0, 3.5));
+
+    assert_eq!(vector, Vector3::new(1.0, -2.0, 3.5));
+}
+
+#[test]
+fn vector_3_into_tuple() {
+    let tuple: (f32, f32, f32) = Vector3::new(1.0, -2.0, 3.5).into();
+
+    assert_eq!(tuple, (1.0, -2.0, 3.5));
+}
+
+#[test]
+fn vector_3_as_array() {
+    let vector = Vector3::new(1.0, -2.0, 3.5);
+
+    assert_eq!(vector.as_array(), &[1.0, -2.0, 3.5]);
+    assert_eq!(std::mem::size_of::<Vector3>(), std::mem::size_of::<[f32; 3]>());
+}
+
+#[test]
+fn vector_3_as_mut_array() {
+    let mut vector = Vector3::new(1.0, -2.0, 3.5);
+    vector.as_mut_array()[2] = 8.0;
+
+    assert_eq!(vector[2], 8.0);
+}
+
+#[test]
+fn vector_3_from_slice() {
+    let vector = Vector3::from_slice(&[1.0, -2.0, 3.5, 9.0]);
+
+    assert_eq!(vector, Vector3::new(1.0, -2.0, 3.5));
+}
+
+#[test]
+#[should_panic]
+fn vector_3_from_slice_too_short() {
+    let _ = Vector3::from_slice(&[1.0; 2]);
+}
+
+#[test]
+fn vector_3_write_to_slice() {
+    let mut buffer = [0.0; 4];
+    Vector3::new(1.0, -2.0, 3.5).write_to_slice(&mut buffer[1..]);
+
+    assert_eq!(buffer, [0.0, 1.0, -2.0, 3.5]);
+}
+
+#[test]
+fn vector_3_iter() {
+    let vector = Vector3::new(1.0, -2.0, 3.5);
+
+    assert_eq!(vector.iter().copied().collect::<Vec<f32>>(), vec![1.0, -2.0, 3.5]);
+}
+
+#[test]
+fn vector_3_iter_mut() {
+    let mut vector = Vector3::new(1.0, -2.0, 3.5);
+    for component in vector.iter_mut() {
+        *component *= 2.0;
+    }
+
+    assert_eq!(vector, Vector3::new(1.0, -2.0, 3.5) * 2.0);
+}
+
+#[test]
+fn vector_3_into_iter() {
+    let mut vector = Vector3::new(1.0, -2.0, 3.5);
+    for component in &mut vector {
+        *component += 1.0;
+    }
+
+    assert_eq!((&vector).into_iter().count(), 3);
+    assert_eq!(vector.into_iter().collect::<Vec<f32>>(), vec![2.0, -1.0, 4.5]);
+}
+
+#[test]
+fn vector_3_from_iter() {
+    let vector: Vector3 = (1..=3).map(|i| i as f32).collect();
+
+    assert_eq!(vector, Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+#[should_panic(expected = "TVector3 must be collected from exactly 3 components")]
+fn vector_3_from_iter_wrong_length() {
+    let _: Vector3 = (0..4).map(|i| i as f32).collect();
+}
+
+#[test]
+fn vector_3_try_from_iter() {
+    assert_eq!(Vector3::try_from_iter(vec![1.0, -2.0, 3.5]), Some(Vector3::new(1.0, -2.0, 3.5)));
+    assert_eq!(Vector3::try_from_iter(vec![1.0; 2]), None);
+    assert_eq!(Vector3::try_from_iter(vec![1.0; 4]), None);
+}
+
+#[test]
+fn vector_3_iter_sum() {
+    let points = [Vector3::new(1.0, -2.0, 3.5), Vector3::from_scalar(1.0), Vector3::from_scalar(-3.0)];
+
+    assert_eq!(points.iter().sum::<Vector3>(), Vector3::new(1.0, -2.0, 3.5) - 2.0);
+    assert_eq!(points.iter().copied().sum::<Vector3>(), Vector3::new(1.0, -2.0, 3.5) - 2.0);
+    assert_eq!(Vec::<Vector3>::new().into_iter().sum::<Vector3>(), Vector3::empty());
+}
+
+#[test]
+fn vector_3_iter_product() {
+    let factors = [Vector3::new(1.0, -2.0, 3.5), Vector3::from_scalar(2.0)];
+
+    assert_eq!(factors.iter().product::<Vector3>(), Vector3::new(1.0, -2.0, 3.5) * 2.0);
+    assert_eq!(factors.iter().copied().product::<Vector3>(), Vector3::new(1.0, -2.0, 3.5) * 2.0);
+    assert_eq!(Vec::<Vector3>::new().into_iter().product::<Vector3>(), Vector3::from_scalar(1.0));
+}
+
+#[test]
+fn vector_3_default() {
+    assert_eq!(Vector3::default(), Vector3::empty());
+}
+
+#[test]
+fn vector_3_hash() {
+    let mut cells = HashMap::new();
+    cells.insert(IVector3::new(1, -2, 3), "filled");
+
+    assert_eq!(cells.get(&IVector3::new(1, -2, 3)), Some(&"filled"));
+    assert_eq!(cells.get(&IVector3::from_scalar(0)), None);
+}
+
+#[test]
+fn vector_3_partial_ord() {
+    assert!(IVector3::new(1, -2, 3) < IVector3::new(1, 100, 100));
+    assert!(IVector3::new(1, -2, 3) > IVector3::new(0, 100, 100));
+    assert_eq!(BVector3::new(false, true, true).partial_cmp(&BVector3::new(true, false, false)), Some(Ordering::Less));
+}
+
+#[test]
+fn vector_3_ord() {
+    let mut vectors = vec![IVector3::from_scalar(2), IVector3::new(1, -2, 3), IVector3::from_scalar(-1)];
+    vectors.sort();
+
+    assert_eq!(vectors, vec![IVector3::from_scalar(-1), IVector3::new(1, -2, 3), IVector3::from_scalar(2)]);
+    assert_eq!(IVector3::new(1, -2, 3).cmp(&IVector3::new(1, -2, 3)), Ordering::Equal);
+}
+
+#[test]
+fn vector_3_ord_min_max() {
+    let first = IVector3::new(1, 5, -1);
+    let second = IVector3::new(2, -3, 0);
+
+    assert_eq!(first.max(second), IVector3::new(2, 5, 0));
+    assert_eq!(first.min(second), IVector3::new(1, -3, -1));
+    assert_eq!(Ord::max(first, second), second);
+    assert_eq!(Ord::min(first, second), first);
+    assert_eq!(Ord::clamp(second, first, first), first);
+}
+
+#[test]
+fn vector_3_display() {
+    let vector = Vector3::new(1.0, -2.5, 3.25);
+
+    assert_eq!(format!("{}", vector), "(1, -2.5, 3.25)");
+    assert_eq!(format!("{:.2}", vector), "(1.00, -2.50, 3.25)");
+}
+
+#[test]
+fn vector_3_from_str() {
+    assert_eq!("(1, -2.5, 3.25)".parse(), Ok(Vector3::new(1.0, -2.5, 3.25)));
+    assert_eq!(" 1.0 -2.5 3.25 ".parse(), Ok(Vector3::new(1.0, -2.5, 3.25)));
+    assert_eq!("(1.0 -2.5 3.25)".parse(), Ok(Vector3::new(1.0, -2.5, 3.25)));
+    assert_eq!("1, -2, 3".replace(",", " ").parse(), Ok(IVector3::new(1, -2, 3)));
+    assert_eq!("(1.0 -2.5 3.25".parse::<Vector3>(), Err(ParseError::UnbalancedParentheses));
+    assert_eq!("(1, 2, 3, 4, 5)".parse::<Vector3>(), Err(ParseError::WrongComponentCount { expected: 3, found: 5 }));
+    assert_eq!("1, x, 3".parse::<Vector3>(), Err(ParseError::InvalidComponent(1)));
+}
@@ -1,4 +1,10 @@
-use iomath::vectors::{ Vector4, UVector4, Vector2, Vector3 };
+use std::convert::TryFrom;
+use std::collections::HashMap;
+use std::cmp::Ordering;
+
+use iomath::vectors::{ Vector4, IVector4, UVector4, Vector2, Vector3 };
+use iomath::vectors::{ CastError, CastErrorKind, ParseError };
+use iomath::extended::vectors::{ BVector4, F32Vector4, F64Vector4, I8Vector4, I16Vector4, I32Vector4, U8Vector4 };
 use iomath::quaternions::Quaternion;
 
 #[test]
@@ -102,10 +108,11 @@ fn vector_4_index() {
 }
 
 #[test]
+#[should_panic(expected = "index out of bounds: TVector4 has 4 components but the index is 4")]
 fn vector_4_index_out_of_bounds() {
     let vector = Vector4::new(7.3, 2.4, -3.8, 4.2);
-    
-    assert_eq!(vector, Vector4::new(vector[0], vector[1], vector[2], vector[256]))
+
+    let _ = vector[4];
 }
 
 #[test]
@@ -120,11 +127,11 @@ fn vector_4_index_mut() {
 }
 
 #[test]
+#[should_panic(expected = "index out of bounds: TVector4 has 4 components but the index is 1024")]
 fn vector_4_index_mut_out_of_bounds() {
-    let mut vector = Vector4::new(4.5, -7.6, 7.2, 15.0);
-    vector[1024] = -1.0;
+    let mut vector = Vector4::new(7.3, 2.4, -3.8, 4.2);
 
-    assert_eq!(vector, Vector4::new(4.5, -7.6, 7.2, -1.0));
+    vector[1024] = -1.0;
 }
 
 #[test]
@@ -463,4 +470,607 @@ fn vector_4_debug_struct() {
     let vector = Vector4::new(11.0, 4.5, -9.0, 5.2);
 
     assert_eq!(format!("{:?}", vector), "TVector4<f32> { x: 11.0, y: 4.5, z: -9.0, w: 5.2 }");
-}
\ No newline at end of file
+}
+
+#[test]
+fn vector_4_dot() {
+    let vector = Vector4::new(1.5, -2.0, 3.0, 0.5);
+    let result = vector.dot(Vector4::new(4.0, 0.5, -1.0, 6.0));
+
+    assert_eq!(result, 5.0);
+}
+
+#[test]
+fn vector_4_length_squared() {
+    let vector = Vector4::new(1.0, -2.0, 2.0, 4.0);
+
+    assert_eq!(vector.length_squared(), 25.0);
+}
+
+#[test]
+fn vector_4_length() {
+    let vector = Vector4::new(1.0, -2.0, 2.0, 4.0);
+
+    assert_eq!(vector.length(), 5.0);
+}
+
+#[test]
+fn vector_4_distance_squared() {
+    let vector = Vector4::new(0.0, 1.0, 2.0, 3.0);
+
+    assert_eq!(vector.distance_squared(Vector4::new(1.0, 3.0, 4.0, 7.0)), 25.0);
+}
+
+#[test]
+fn vector_4_distance() {
+    let vector = Vector4::new(0.0, 1.0, 2.0, 3.0);
+
+    assert_eq!(vector.distance(Vector4::new(1.0, 3.0, 4.0, 7.0)), 5.0);
+}
+
+#[test]
+fn vector_4_normalize() {
+    let vector = Vector4::new(0.0, 3.0, 0.0, -4.0);
+
+    assert_eq!(vector.normalize(), Vector4::new(0.0, 0.6, 0.0, -0.8));
+}
+
+#[test]
+fn vector_4_try_normalize() {
+    let vector = Vector4::new(0.0, 0.0, 0.0, 0.25);
+
+    assert_eq!(vector.try_normalize(), Some(Vector4::new(0.0, 0.0, 0.0, 1.0)));
+}
+
+#[test]
+fn vector_4_try_normalize_zero() {
+    let vector = Vector4::empty();
+
+    assert_eq!(vector.try_normalize(), None);
+}
+
+#[test]
+fn vector_4_get() {
+    let vector = Vector4::new(7.3, 2.4, -3.8, 4.2);
+
+    assert_eq!(vector.get(0), Some(&7.3));
+    assert_eq!(vector.get(3), Some(&4.2));
+    assert_eq!(vector.get(4), None);
+}
+
+#[test]
+fn vector_4_get_mut() {
+    let mut vector = Vector4::new(7.3, 2.4, -3.8, 4.2);
+    *vector.get_mut(1).unwrap() = 0.5;
+
+    assert_eq!(vector[1], 0.5);
+    assert_eq!(vector.get_mut(4), None);
+}
+
+#[test]
+fn vector_4_index_range() {
+    let vector = Vector4::new(7.3, 2.4, -3.8, 4.2);
+
+    assert_eq!(&vector[..], &[7.3, 2.4, -3.8, 4.2]);
+    assert_eq!(&vector[1..], &[2.4, -3.8, 4.2]);
+    assert_eq!(&vector[..1], &[7.3]);
+    assert_eq!(&vector[0..=1], &[7.3, 2.4]);
+    assert_eq!(&vector[..=3], &[7.3, 2.4, -3.8, 4.2]);
+    assert_eq!(&vector[1..4], &[2.4, -3.8, 4.2]);
+}
+
+#[test]
+fn vector_4_index_range_mut() {
+    let mut vector = Vector4::new(7.3, 2.4, -3.8, 4.2);
+    vector[..2].copy_from_slice(&[1.0, 2.0]);
+
+    assert_eq!(vector[0], 1.0);
+    assert_eq!(vector[1], 2.0);
+    assert_eq!(vector[3], 4.2);
+}
+
+#[test]
+#[should_panic(expected = "range end index 5 out of range for slice of length 4")]
+fn vector_4_index_range_out_of_bounds() {
+    let vector = Vector4::new(7.3, 2.4, -3.8, 4.2);
+
+    let _ = &vector[1..5];
+}
+
+#[test]
+fn vector_4_swizzle() {
+    let vector = Vector4::new(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(vector.wx(), Vector2::new(4.0, 1.0));
+    assert_eq!(vector.xyz(), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(vector.wzyx(), Vector4::new(4.0, 3.0, 2.0, 1.0));
+    assert_eq!(vector.xxxx(), Vector4::from_scalar(1.0));
+    assert_eq!(vector.rgba(), vector);
+    assert_eq!(vector.qpts(), vector.wzyx());
+}
+
+#[test]
+fn vector_4_set_swizzle() {
+    let mut vector = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    vector.set_wy(Vector2::new(-4.0, -2.0));
+
+    assert_eq!(vector, Vector4::new(1.0, -2.0, 3.0, -4.0));
+
+    vector.set_zxy(Vector3::new(5.0, 6.0, 7.0));
+
+    assert_eq!(vector, Vector4::new(6.0, 7.0, 5.0, -4.0));
+
+    vector.set_abgr(Vector4::new(1.0, 2.0, 3.0, 4.0));
+
+    assert_eq!(vector, Vector4::new(4.0, 3.0, 2.0, 1.0));
+
+    vector.set_stp(Vector3::new(0.0, 0.5, 1.5));
+
+    assert_eq!(vector, Vector4::new(0.0, 0.5, 1.5, 1.0));
+}
+
+#[test]
+fn vector_4_comparisons() {
+    let first = Vector4::new(1.0, -2.0, 5.0, 0.0);
+    let second = Vector4::new(1.0, 3.0, 4.0, 0.0);
+
+    assert_eq!(first.cmplt(second), BVector4::new(false, true, false, false));
+    assert_eq!(first.cmple(second), BVector4::new(true, true, false, true));
+    assert_eq!(first.cmpeq(second), BVector4::new(true, false, false, true));
+    assert_eq!(first.cmpne(second), BVector4::new(false, true, true, false));
+    assert_eq!(first.cmpge(second), BVector4::new(true, false, true, true));
+    assert_eq!(first.cmpgt(second), BVector4::new(false, false, true, false));
+}
+
+#[test]
+fn vector_4_any_all_none() {
+    assert!(BVector4::new(true, false, false, false).any());
+    assert!(!BVector4::new(true, false, false, false).all());
+    assert!(!BVector4::new(true, false, false, false).none());
+    assert!(BVector4::new(true, true, true, true).all());
+    assert!(BVector4::new(false, false, false, false).none());
+    assert!(!BVector4::new(false, false, false, false).any());
+}
+
+#[test]
+fn vector_4_not() {
+    assert_eq!(!BVector4::new(true, false, false, false), BVector4::new(false, true, true, true));
+    assert_eq!(!UVector4::from_scalar(0), UVector4::from_scalar(u32::MAX));
+}
+
+#[test]
+fn vector_4_select() {
+    let first = Vector4::new(1.0, -2.0, 5.0, 0.0);
+    let second = Vector4::new(1.0, 3.0, 4.0, 0.0);
+
+    assert_eq!(Vector4::select(first.cmplt(second), first, second), Vector4::new(1.0, -2.0, 4.0, 0.0));
+    assert_eq!(Vector4::select(BVector4::new(true, false, false, false), first, second), Vector4::new(1.0, 3.0, 4.0, 0.0));
+}
+
+#[test]
+fn vector_4_abs() {
+    let vector = Vector4::new(-1.5, 2.25, -0.75, 4.0);
+
+    assert_eq!(vector.abs(), Vector4::new(1.5, 2.25, 0.75, 4.0));
+    assert_eq!(IVector4::from_scalar(-3).abs(), IVector4::from_scalar(3));
+}
+
+#[test]
+fn vector_4_min() {
+    let vector = Vector4::new(-1.5, 2.25, -0.75, 4.0);
+
+    assert_eq!(vector.min(Vector4::new(0.5, 3.0, -2.0, 4.5)), Vector4::new(-1.5, 2.25, -2.0, 4.0));
+}
+
+#[test]
+fn vector_4_max() {
+    let vector = Vector4::new(-1.5, 2.25, -0.75, 4.0);
+
+    assert_eq!(vector.max(Vector4::new(0.5, 3.0, -2.0, 4.5)), Vector4::new(0.5, 3.0, -0.75, 4.5));
+}
+
+#[test]
+fn vector_4_clamp() {
+    let vector = Vector4::new(-1.5, 2.25, -0.75, 4.0);
+
+    assert_eq!(vector.clamp(Vector4::from_scalar(-1.0), Vector4::from_scalar(1.0)), Vector4::new(-1.0, 1.0, -0.75, 1.0));
+}
+
+#[test]
+fn vector_4_floor() {
+    let vector = Vector4::new(-2.5, 1.5, 0.25, -0.5);
+
+    assert_eq!(vector.floor(), Vector4::new(-3.0, 1.0, 0.0, -1.0));
+}
+
+#[test]
+fn vector_4_ceil() {
+    let vector = Vector4::new(-2.5, 1.5, 0.25, -0.5);
+
+    assert_eq!(vector.ceil(), Vector4::new(-2.0, 2.0, 1.0, 0.0));
+}
+
+#[test]
+fn vector_4_round() {
+    let vector = Vector4::new(-2.5, 1.5, 0.25, -0.5);
+
+    assert_eq!(vector.round(), Vector4::new(-3.0, 2.0, 0.0, -1.0));
+}
+
+#[test]
+fn vector_4_trunc() {
+    let vector = Vector4::new(-2.5, 1.5, 0.25, -0.5);
+
+    assert_eq!(vector.trunc(), Vector4::new(-2.0, 1.0, 0.0, 0.0));
+}
+
+#[test]
+fn vector_4_fract() {
+    let vector = Vector4::new(-2.5, 1.5, 0.25, -0.5);
+
+    assert_eq!(vector.fract(), Vector4::new(-0.5, 0.5, 0.25, -0.5));
+}
+
+#[test]
+fn vector_4_signum() {
+    let vector = Vector4::new(-2.5, 1.5, 0.25, -0.5);
+
+    assert_eq!(vector.signum(), Vector4::new(-1.0, 1.0, 1.0, -1.0));
+    assert_eq!(IVector4::from_scalar(0).signum(), IVector4::from_scalar(0));
+}
+
+#[test]
+fn vector_4_copysign() {
+    let vector = Vector4::new(-1.5, 2.25, -0.75, 4.0);
+
+    assert_eq!(vector.copysign(Vector4::new(0.5, 3.0, -2.0, 4.5)), Vector4::new(1.5, 2.25, -0.75, 4.0));
+}
+
+#[test]
+fn vector_4_recip() {
+    let vector = Vector4::new(4.0, 0.25, 16.0, 1.0);
+
+    assert_eq!(vector.recip(), Vector4::new(0.25, 4.0, 0.0625, 1.0));
+}
+
+#[test]
+fn vector_4_sqrt() {
+    let vector = Vector4::new(4.0, 0.25, 16.0, 1.0);
+
+    assert_eq!(vector.sqrt(), Vector4::new(2.0, 0.5, 4.0, 1.0));
+}
+
+#[test]
+fn vector_4_powf() {
+    let vector = Vector4::new(4.0, 0.25, 16.0, 1.0);
+
+    assert_eq!(vector.powf(0.5), vector.sqrt());
+    assert_eq!(vector.powf(2.0), vector * vector);
+}
+
+#[test]
+fn vector_4_exp() {
+    let vector = Vector4::from_scalar(0.0);
+
+    assert_eq!(vector.exp(), Vector4::from_scalar(1.0));
+    assert!((Vector4::from_scalar(1.0).exp() - Vector4::from_scalar(std::f32::consts::E)).abs().max_element() < 1e-6);
+}
+
+#[test]
+fn vector_4_ln() {
+    let vector = Vector4::from_scalar(1.0);
+
+    assert_eq!(vector.ln(), Vector4::from_scalar(0.0));
+    assert!((Vector4::from_scalar(std::f32::consts::E).ln() - vector).abs().max_element() < 1e-6);
+}
+
+#[test]
+fn vector_4_mul_add() {
+    let vector = Vector4::new(-1.5, 2.25, -0.75, 4.0);
+
+    assert_eq!(vector.mul_add(Vector4::new(0.5, 3.0, -2.0, 4.5), Vector4::new(-2.5, 1.5, 0.25, -0.5)), Vector4::new(-3.25, 8.25, 1.75, 17.5));
+}
+
+#[test]
+fn vector_4_min_element() {
+    let vector = Vector4::new(0.5, 3.0, -2.0, 4.5);
+
+    assert_eq!(vector.min_element(), -2.0);
+}
+
+#[test]
+fn vector_4_max_element() {
+    let vector = Vector4::new(0.5, 3.0, -2.0, 4.5);
+
+    assert_eq!(vector.max_element(), 4.5);
+}
+
+#[test]
+fn vector_4_sum() {
+    let vector = Vector4::new(0.5, 3.0, -2.0, 4.5);
+
+    assert_eq!(vector.sum(), 6.0);
+}
+
+#[test]
+fn vector_4_product() {
+    let vector = Vector4::new(0.5, 3.0, -2.0, 4.5);
+
+    assert_eq!(vector.product(), -13.5);
+}
+
+#[test]
+fn vector_4_reflect() {
+    let vector = Vector4::new(1.0, -1.0, 0.0, 0.0);
+
+    assert_eq!(vector.reflect(Vector4::new(0.0, 1.0, 0.0, 0.0)), Vector4::new(1.0, 1.0, 0.0, 0.0));
+}
+
+#[test]
+fn vector_4_refract() {
+    let vector = Vector4::new(0.6, -0.8, 0.0, 0.0);
+    let normal = Vector4::new(0.0, 1.0, 0.0, 0.0);
+    let refracted = vector.refract(normal, 0.5).unwrap();
+
+    assert_eq!(vector.refract(normal, 1.0), Some(vector));
+    assert!((refracted.x - 0.3).abs() < 1e-6);
+    assert!((refracted.length() - 1.0).abs() < 1e-6);
+    assert!(refracted.y < 0.0);
+    assert_eq!(vector.refract(normal, 2.0), None);
+}
+
+#[test]
+fn vector_4_project_onto() {
+    let vector = Vector4::new(2.0, 3.0, 4.0, 5.0);
+
+    assert_eq!(vector.project_onto(Vector4::new(0.0, 2.0, 0.0, 0.0)), Vector4::new(0.0, 3.0, 0.0, 0.0));
+}
+
+#[test]
+fn vector_4_reject_from() {
+    let vector = Vector4::new(2.0, 3.0, 4.0, 5.0);
+
+    assert_eq!(vector.reject_from(Vector4::new(0.0, 2.0, 0.0, 0.0)), Vector4::new(2.0, 0.0, 4.0, 5.0));
+}
+
+#[test]
+fn vector_4_face_forward() {
+    let normal = Vector4::new(0.0, 1.0, 0.0, 0.0);
+
+    assert_eq!(normal.face_forward(Vector4::new(0.0, -1.0, 0.0, 0.0), normal), normal);
+    assert_eq!(normal.face_forward(Vector4::new(0.0, 1.0, 0.0, 0.0), normal), -normal);
+}
+
+#[test]
+fn vector_4_angle_between() {
+    let vector = Vector4::new(1.0, 0.0, 0.0, 0.0);
+
+    assert!((vector.angle_between(Vector4::new(0.0, 1.0, 0.0, 0.0)) - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    assert!((vector.angle_between(Vector4::new(-1.0, 0.0, 0.0, 0.0)) - std::f32::consts::PI).abs() < 1e-6);
+    assert_eq!(vector.angle_between(vector * 3.0), 0.0);
+    assert!((F64Vector4::new(1.0, 0.0, 0.0, 0.0).angle_between(F64Vector4::new(1.0, 1e-07, 0.0, 0.0)) - 1e-7).abs() < 1e-15);
+}
+
+#[test]
+fn vector_4_as() {
+    let vector = Vector4::new(1.75, -2.5, 300.0, 0.25);
+
+    assert_eq!(vector.as_i32(), IVector4::new(1, -2, 300, 0));
+    assert_eq!(vector.as_u8(), U8Vector4 { x: 1, y: 0, z: 255, w: 0 });
+    assert_eq!(IVector4::new(-3, 7, 1000, 0).as_f64(), F64Vector4::new(-3.0, 7.0, 1000.0, 0.0));
+}
+
+#[test]
+fn vector_4_from_lossless() {
+    assert_eq!(I32Vector4::from(I16Vector4::new(-3, 7, 1000, 0)), I32Vector4::new(-3, 7, 1000, 0));
+    assert_eq!(F32Vector4::from(U8Vector4 { x: 1, y: 2, z: 3, w: 255 }), F32Vector4::new(1.0, 2.0, 3.0, 255.0));
+    assert_eq!(F64Vector4::from(Vector4::new(1.75, -2.5, 300.0, 0.25)), F64Vector4::new(1.75, -2.5, 300.0, 0.25));
+}
+
+#[test]
+fn vector_4_try_from() {
+    assert_eq!(U8Vector4::try_from(IVector4::new(1, 2, 3, 255)), Ok(U8Vector4 { x: 1, y: 2, z: 3, w: 255 }));
+    assert_eq!(U8Vector4::try_from(IVector4::new(1, 2, 3, 256)), Err(CastError { component: 3, kind: CastErrorKind::Overflow }));
+    assert_eq!(I8Vector4::try_from(IVector4::new(-129, 0, 0, 0)), Err(CastError { component: 0, kind: CastErrorKind::Overflow }));
+    assert_eq!(IVector4::try_from(Vector4::new(1.0, -2.0, 300.0, 0.0)), Ok(IVector4::new(1, -2, 300, 0)));
+    assert_eq!(IVector4::try_from(Vector4::new(1.0, -2.0, 300.0, 0.25)), Err(CastError { component: 3, kind: CastErrorKind::PrecisionLoss }));
+    assert_eq!(IVector4::try_from(Vector4::new(1.0, f32::NAN, f32::INFINITY, 0.0)), Err(CastError { component: 1, kind: CastErrorKind::NonFinite }));
+    assert_eq!(IVector4::try_from(Vector4::new(2147483648.0, 0.0, 0.0, 0.0)), Err(CastError { component: 0, kind: CastErrorKind::Overflow }));
+    assert_eq!(Vector4::try_from(F64Vector4::new(1e300, 0.0, 0.0, 0.0)), Err(CastError { component: 0, kind: CastErrorKind::Overflow }));
+    assert!(Vector4::try_from(F64Vector4::new(0.5, -2.0, f64::INFINITY, f64::NAN)).is_ok());
+    assert_eq!(Vector4::try_from(F64Vector4::new(0.0, 0.0, 0.0, 0.1)), Err(CastError { component: 3, kind: CastErrorKind::PrecisionLoss }));
+    assert_eq!(Vector4::try_from(IVector4::new(16_777_216, -3, 0, 1)), Ok(Vector4::new(16777216.0, -3.0, 0.0, 1.0)));
+    assert_eq!(Vector4::try_from(IVector4::new(0, 0, 0, 16_777_217)), Err(CastError { component: 3, kind: CastErrorKind::PrecisionLoss }));
+}
+
+#[test]
+fn vector_4_from_array() {
+    let vector = Vector4::from([1.0, -2.0, 3.5, 0.25]);
+
+    assert_eq!(vector, Vector4::new(1.0, -2.0, 3.5, 0.25));
+}
+
+#[test]
+fn vector_4_into_array() {
+    let array: [f32; 4] = Vector4::new(1.0, -2.0, 3.5, 0.25).into();
+
+    assert_eq!(array, [1.0, -2.0, 3.5, 0.25]);
+}
+
+#[test]
+fn vector_4_from_tuple() {
+    let vector = Vector4::from((1.0, -2.0, 3.5, 0.25));
+
+    assert_eq!(vector, Vector4::new(1.0, -2.0, 3.5, 0.25));
+}
+
+#[test]
+fn vector_4_into_tuple() {
+    let tuple: (f32, f32, f32, f32) = Vector4::new(1.0, -2.0, 3.5, 0.25).into();
+
+    assert_eq!(tuple, (1.0, -2.0, 3.5, 0.25));
+}
+
+#[test]
+fn vector_4_as_array() {
+    let vector = Vector4::new(1.0, -2.0, 3.5, 0.25);
+
+    assert_eq!(vector.as_array(), &[1.0, -2.0, 3.5, 0.25]);
+    assert_eq!(std::mem::size_of::<Vector4>(), std::mem::size_of::<[f32; 4]>());
+}
+
+#[test]
+fn vector_4_as_mut_array() {
+    let mut vector = Vector4::new(1.0, -2.0, 3.5, 0.25);
+    vector.as_mut_array()[3] = 8.0;
+
+    assert_eq!(vector[3], 8.0);
+}
+
+#[test]
+fn vector_4_from_slice() {
+    let vector = Vector4::from_slice(&[1.0, -2.0, 3.5, 0.25, 9.0]);
+
+    assert_eq!(vector, Vector4::new(1.0, -2.0, 3.5, 0.25));
+}
+
+#[test]
+#[should_panic]
+fn vector_4_from_slice_too_short() {
+    let _ = Vector4::from_slice(&[1.0; 3]);
+}
+
+#[test]
+fn vector_4_write_to_slice() {
+    let mut buffer = [0.0; 5];
+    Vector4::new(1.0, -2.0, 3.5, 0.25).write_to_slice(&mut buffer[1..]);
+
+    assert_eq!(buffer, [0.0, 1.0, -2.0, 3.5, 0.25]);
+}
+
+#[test]
+fn vector_4_iter() {
+    let vector = Vector4::new(1.0, -2.0, 3.5, 0.25);
+
+    assert_eq!(vector.iter().copied().collect::<Vec<f32>>(), vec![1.0, -2.0, 3.5, 0.25]);
+}
+
+#[test]
+fn vector_4_iter_mut() {
+    let mut vector = Vector4::new(1.0, -2.0, 3.5, 0.25);
+    for component in vector.iter_mut() {
+        *component *= 2.0;
+    }
+
+    assert_eq!(vector, Vector4::new(1.0, -2.0, 3.5, 0.25) * 2.0);
+}
+
+#[test]
+fn vector_4_into_iter() {
+    let mut vector = Vector4::new(1.0, -2.0, 3.5, 0.25);
+    for component in &mut vector {
+        *component += 1.0;
+    }
+
+    assert_eq!((&vector).into_iter().count(), 4);
+    assert_eq!(vector.into_iter().collect::<Vec<f32>>(), vec![2.0, -1.0, 4.5, 1.25]);
+}
+
+#[test]
+fn vector_4_from_iter() {
+    let vector: Vector4 = (1..=4).map(|i| i as f32).collect();
+
+    assert_eq!(vector, Vector4::new(1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+#[should_panic(expected = "TVector4 must be collected from exactly 4 components")]
+fn vector_4_from_iter_wrong_length() {
+    let _: Vector4 = (0..5).map(|i| i as f32).collect();
+}
+
+#[test]
+fn vector_4_try_from_iter() {
+    assert_eq!(Vector4::try_from_iter(vec![1.0, -2.0, 3.5, 0.25]), Some(Vector4::new(1.0, -2.0, 3.5, 0.25)));
+    assert_eq!(Vector4::try_from_iter(vec![1.0; 3]), None);
+    assert_eq!(Vector4::try_from_iter(vec![1.0; 5]), None);
+}
+
+#[test]
+fn vector_4_iter_sum() {
+    let points = [Vector4::new(1.0, -2.0, 3.5, 0.25), Vector4::from_scalar(1.0), Vector4::from_scalar(-3.0)];
+
+    assert_eq!(points.iter().sum::<Vector4>(), Vector4::new(1.0, -2.0, 3.5, 0.25) - 2.0);
+    assert_eq!(points.iter().copied().sum::<Vector4>(), Vector4::new(1.0, -2.0, 3.5, 0.25) - 2.0);
+    assert_eq!(Vec::<Vector4>::new().into_iter().sum::<Vector4>(), Vector4::empty());
+}
+
+#[test]
+fn vector_4_iter_product() {
+    let factors = [Vector4::new(1.0, -2.0, 3.5, 0.25), Vector4::from_scalar(2.0)];
+
+    assert_eq!(factors.iter().product::<Vector4>(), Vector4::new(1.0, -2.0, 3.5, 0.25) * 2.0);
+    assert_eq!(factors.iter().copied().product::<Vector4>(), Vector4::new(1.0, -2.0, 3.5, 0.25) * 2.0);
+    assert_eq!(Vec::<Vector4>::new().into_iter().product::<Vector4>(), Vector4::from_scalar(1.0));
+}
+
+#[test]
+fn vector_4_default() {
+    assert_eq!(Vector4::default(), Vector4::empty());
+}
+
+#[test]
+fn vector_4_hash() {
+    let mut cells = HashMap::new();
+    cells.insert(IVector4::new(1, -2, 3, 4), "filled");
+
+    assert_eq!(cells.get(&IVector4::new(1, -2, 3, 4)), Some(&"filled"));
+    assert_eq!(cells.get(&IVector4::from_scalar(0)), None);
+}
+
+#[test]
+fn vector_4_partial_ord() {
+    assert!(IVector4::new(1, -2, 3, 4) < IVector4::new(1, 100, 100, 100));
+    assert!(IVector4::new(1, -2, 3, 4) > IVector4::new(0, 100, 100, 100));
+    assert_eq!(BVector4::new(false, true, true, true).partial_cmp(&BVector4::new(true, false, false, false)), Some(Ordering::Less));
+}
+
+#[test]
+fn vector_4_ord() {
+    let mut vectors = vec![IVector4::from_scalar(2), IVector4::new(1, -2, 3, 4), IVector4::from_scalar(-1)];
+    vectors.sort();
+
+    assert_eq!(vectors, vec![IVector4::from_scalar(-1), IVector4::new(1, -2, 3, 4), IVector4::from_scalar(2)]);
+    assert_eq!(IVector4::new(1, -2, 3, 4).cmp(&IVector4::new(1, -2, 3, 4)), Ordering::Equal);
+}
+
+#[test]
+fn vector_4_ord_min_max() {
+    let first = IVector4::new(1, 5, -1, 7);
+    let second = IVector4::new(2, -3, 0, 0);
+
+    assert_eq!(first.max(second), IVector4::new(2, 5, 0, 7));
+    assert_eq!(first.min(second), IVector4::new(1, -3, -1, 0));
+    assert_eq!(Ord::max(first, second), second);
+    assert_eq!(Ord::min(first, second), first);
+    assert_eq!(Ord::clamp(second, first, first), first);
+}
+
+#[test]
+fn vector_4_display() {
+    let vector = Vector4::new(1.0, -2.5, 3.25, 0.5);
+
+    assert_eq!(format!("{}", vector), "(1, -2.5, 3.25, 0.5)");
+    assert_eq!(format!("{:.2}", vector), "(1.00, -2.50, 3.25, 0.50)");
+}
+
+#[test]
+fn vector_4_from_str() {
+    assert_eq!("(1, -2.5, 3.25, 0.5)".parse(), Ok(Vector4::new(1.0, -2.5, 3.25, 0.5)));
+    assert_eq!(" 1.0 -2.5 3.25 0.5 ".parse(), Ok(Vector4::new(1.0, -2.5, 3.25, 0.5)));
+    assert_eq!("(1.0 -2.5 3.25 0.5)".parse(), Ok(Vector4::new(1.0, -2.5, 3.25, 0.5)));
+    assert_eq!("1, -2, 3, 4".replace(",", " ").parse(), Ok(IVector4::new(1, -2, 3, 4)));
+    assert_eq!("(1.0 -2.5 3.25 0.5".parse::<Vector4>(), Err(ParseError::UnbalancedParentheses));
+    assert_eq!("(1, 2, 3, 4, 5)".parse::<Vector4>(), Err(ParseError::WrongComponentCount { expected: 4, found: 5 }));
+    assert_eq!("1, x, 3, 4".parse::<Vector4>(), Err(ParseError::InvalidComponent(1)));
+}